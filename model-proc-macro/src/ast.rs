@@ -1,9 +1,11 @@
+mod data_type;
 mod field;
+mod identifier;
 mod model;
 mod model_options;
-mod identifier;
 
+pub use data_type::*;
 pub use field::*;
+pub use identifier::*;
 pub use model::*;
 pub use model_options::*;
-pub use identifier::*;
\ No newline at end of file
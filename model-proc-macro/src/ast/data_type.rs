@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use super::Identifier;
+
+/// The rust type that was declared for a field, only a subset of the rust types
+/// is supported: paths with optional generics, slices and tuples.
+#[derive(Debug, Clone)]
+pub enum DataType {
+  Path {
+    segments: Vec<Identifier>,
+    generics: Vec<DataType>,
+  },
+  /// A `[T]`, emitted as a `Vec<T>` since the generated structs cannot hold an
+  /// unsized field
+  Slice(Box<DataType>),
+  Tuple(Vec<DataType>),
+}
+
+impl DataType {
+  /// Best-effort mapping of the rust type to a SurrealDB type, returns `None`
+  /// when no SurrealDB type matches.
+  pub fn to_surreal_type(&self) -> Option<String> {
+    let inner = |generics: &Vec<DataType>| {
+      generics
+        .first()
+        .and_then(|generic| generic.to_surreal_type())
+        .unwrap_or_else(|| String::from("any"))
+    };
+
+    match self {
+      DataType::Path { segments, generics } => {
+        let name = segments.last()?.as_ref();
+
+        let surreal_type = match name {
+          "String" | "str" => String::from("string"),
+          "bool" => String::from("bool"),
+          "f32" | "f64" => String::from("float"),
+          "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+          | "u128" | "usize" => String::from("int"),
+          "Option" => format!("option<{}>", inner(generics)),
+          "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => format!("array<{}>", inner(generics)),
+          "HashMap" | "BTreeMap" | "Map" => String::from("object"),
+          "Thing" => String::from("record"),
+          "Datetime" | "DateTime" => String::from("datetime"),
+          "Duration" => String::from("duration"),
+          "Value" => String::from("any"),
+          _ => return None,
+        };
+
+        Some(surreal_type)
+      }
+      DataType::Slice(inner) => Some(format!(
+        "array<{}>",
+        inner
+          .to_surreal_type()
+          .unwrap_or_else(|| String::from("any"))
+      )),
+      DataType::Tuple(_) => Some(String::from("array")),
+    }
+  }
+
+  /// Whether the type is a collection of values
+  pub fn is_array(&self) -> bool {
+    match self {
+      DataType::Path { segments, .. } => segments
+        .last()
+        .is_some_and(|name| name == "Vec" || name == "VecDeque" || name == "ForeignVec"),
+      DataType::Slice(_) => true,
+      DataType::Tuple(_) => false,
+    }
+  }
+
+  /// Whether the type is an `Option<T>`
+  pub fn is_option(&self) -> bool {
+    match self {
+      DataType::Path { segments, .. } => segments.last().is_some_and(|name| name == "Option"),
+      _ => false,
+    }
+  }
+}
+
+impl Display for DataType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      DataType::Path { segments, generics } => {
+        for (i, segment) in segments.iter().enumerate() {
+          if i > 0 {
+            write!(f, "::")?;
+          }
+
+          write!(f, "{segment}")?;
+        }
+
+        if !generics.is_empty() {
+          write!(f, "<")?;
+          write_separated(f, generics)?;
+          write!(f, ">")?;
+        }
+
+        Ok(())
+      }
+      DataType::Slice(inner) => write!(f, "Vec<{inner}>"),
+      DataType::Tuple(types) => {
+        write!(f, "(")?;
+        write_separated(f, types)?;
+
+        if types.len() == 1 {
+          write!(f, ",")?;
+        }
+
+        write!(f, ")")
+      }
+    }
+  }
+}
+
+fn write_separated(f: &mut std::fmt::Formatter<'_>, types: &[DataType]) -> std::fmt::Result {
+  for (i, data_type) in types.iter().enumerate() {
+    if i > 0 {
+      write!(f, ", ")?;
+    }
+
+    write!(f, "{data_type}")?;
+  }
+
+  Ok(())
+}
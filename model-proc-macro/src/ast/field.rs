@@ -1,6 +1,9 @@
+use std::str::FromStr;
+
 use quote::__private::TokenStream;
 use quote::quote;
 
+use super::DataType;
 use super::Identifier;
 
 #[derive(Debug, Clone)]
@@ -12,11 +15,20 @@ pub enum Field {
 
 impl Field {
   pub fn emit_field(&self) -> TokenStream {
-    match self {
-      Field::Property(x) => x.emit_field(),
-      Field::ForeignNode(x) => x.emit_field(),
-      Field::Relation(x) => x.emit_field(),
-    }
+    let (docs, field) = match self {
+      Field::Property(x) => (&x.docs, x.emit_field()),
+      Field::ForeignNode(x) => (&x.docs, x.emit_field()),
+      Field::Relation(x) => (&x.docs, x.emit_field()),
+    };
+
+    let docs = docs
+      .iter()
+      .map(|literal| TokenStream::from_str(literal).unwrap());
+
+    quote!(
+      #(#[doc = #docs])*
+      #field
+    )
   }
 
   pub fn emit_initialization(&self) -> TokenStream {
@@ -43,32 +55,140 @@ impl Field {
     }
   }
 
+  /// The name under which the field is selected in a query: the database name
+  /// for properties and foreign nodes, the alias for relations.
+  pub fn column_name(&self) -> &str {
+    match self {
+      Field::Property(p) => p.db_name(),
+      Field::ForeignNode(f) => f.db_name(),
+      Field::Relation(r) => r.alias.as_ref(),
+    }
+  }
+
+  /// Emit the field for the generated data struct, fields without a declared
+  /// type are not part of the data struct. The only exception is the `id` field
+  /// which defaults to `Option<IdType>` when the model declares its `id_type`.
+  pub fn emit_data_field(&self, id_type: Option<&DataType>) -> TokenStream {
+    let (name, rename, data_type) = match self {
+      Field::Property(p) => (&p.name, &p.rename, &p.data_type),
+      Field::ForeignNode(f) => (&f.name, &f.rename, &f.data_type),
+      Field::Relation(_) => return quote!(),
+    };
+
+    let data_type = match (data_type, id_type) {
+      (Some(data_type), _) => data_type.to_string(),
+      (None, Some(id_type)) if name == "id" => format!("Option<{id_type}>"),
+      (None, _) => return quote!(),
+    };
+
+    let name = name.to_ident();
+    let data_type = TokenStream::from_str(&data_type).unwrap();
+    let attribute = match rename {
+      Some(rename) => quote!(#[serde(rename = #rename)]),
+      None => quote!(),
+    };
+
+    quote!(
+      #attribute
+      pub #name: #data_type,
+    )
+  }
+
+  /// Emit the `DEFINE FIELD` statement of the field for the given `table`, the
+  /// SurrealDB type is inferred from the declared type or foreign node if any.
+  /// Relations and the `id` field do not produce any statement.
+  pub fn emit_define_statement(&self, table: &str) -> Option<String> {
+    let (name, surreal_type) = match self {
+      Field::Property(p) => (
+        p.db_name(),
+        p.data_type.as_ref().and_then(DataType::to_surreal_type),
+      ),
+      Field::ForeignNode(f) => (f.db_name(), Some(f.surreal_type())),
+      Field::Relation(_) => return None,
+    };
+
+    if name == "id" {
+      return None;
+    }
+
+    let statement = match surreal_type {
+      Some(surreal_type) => format!("DEFINE FIELD {name} ON {table} TYPE {surreal_type};"),
+      None => format!("DEFINE FIELD {name} ON {table};"),
+    };
+
+    Some(statement)
+  }
+
+  /// Emit the items the field needs at the module level, like the edge struct
+  /// of a relation with properties.
+  pub fn emit_module_declaration(&self) -> TokenStream {
+    match self {
+      Field::Relation(x) => x.emit_edge_declaration(),
+      _ => quote!(),
+    }
+  }
+
+  /// Emit the setter of the field for the partial builder. If the field has a
+  /// declared type then the setter expects a value of that type, otherwise any
+  /// serializable value is accepted.
   pub fn emit_partial_setter_field_function(&self) -> TokenStream {
-    let field_name = match self {
-      Field::Property(p) => &p.name,
-      Field::ForeignNode(f) => &f.name,
-      Field::Relation(r) => &r.name,
+    let (field_name, db_name, data_type) = match self {
+      Field::Property(p) => (&p.name, p.db_name(), &p.data_type),
+      Field::ForeignNode(f) => (&f.name, f.db_name(), &f.data_type),
+      Field::Relation(r) => (&r.name, r.name.as_ref(), &None),
     };
 
     let name = field_name.to_ident();
 
-    quote!(
-      pub fn #name (mut self, value: impl serde::Serialize) -> Self {
-        self.__insert_value_result(stringify!(#name), value)
+    match data_type {
+      Some(data_type) => {
+        let doc = format!(" Sets the `{db_name}` field, expects a `{data_type}`");
+        let data_type = TokenStream::from_str(&data_type.to_string()).unwrap();
+
+        quote!(
+          #[doc = #doc]
+          pub fn #name (mut self, value: #data_type) -> Self {
+            self.__insert_value_result(#db_name, value)
+          }
+        )
       }
-    )
+      None => {
+        let doc = format!(" Sets the `{db_name}` field");
+
+        quote!(
+          #[doc = #doc]
+          pub fn #name (mut self, value: impl serde::Serialize) -> Self {
+            self.__insert_value_result(#db_name, value)
+          }
+        )
+      }
+    }
   }
 }
 
 /// A simple property
 #[derive(Debug, Clone)]
 pub struct FieldProperty {
+  /// the doc comments of the field, as string literals
+  pub docs: Vec<String>,
+
   pub name: Identifier,
 
   pub is_public: bool,
+
+  /// the name of the field in the database if it differs from the rust name
+  pub rename: Option<String>,
+
+  /// the rust type of the field, if any was declared
+  pub data_type: Option<DataType>,
 }
 
 impl FieldProperty {
+  /// The identifier of the field in the database
+  pub fn db_name(&self) -> &str {
+    self.rename.as_deref().unwrap_or(self.name.as_ref())
+  }
+
   fn emit_field(&self) -> TokenStream {
     let name = self.name.to_ident();
     let attribute = match self.is_public {
@@ -85,16 +205,19 @@ impl FieldProperty {
 
   pub fn emit_initialization(&self) -> TokenStream {
     let name = self.name.to_ident();
-    let name_str: &str = self.name.as_ref();
+    let name_str = self.db_name();
 
-    quote!(#name: SchemaField::new(#name_str, SchemaFieldType::Property))
+    quote!(#name: SchemaField::new(#name_str, SchemaFieldType::Property).with_table(Self::label))
   }
 
   pub fn emit_initialization_with_origin(&self) -> TokenStream {
     let name = self.name.to_ident();
-    let name_str: &str = self.name.as_ref();
+    let name_str = self.db_name();
 
-    quote!(#name: SchemaField::with_origin(#name_str, SchemaFieldType::Property, origin.clone()))
+    quote!(
+      #name: SchemaField::with_origin(#name_str, SchemaFieldType::Property, origin.clone())
+        .with_table(Self::label)
+    )
   }
 
   pub fn emit_foreign_field_function(&self) -> TokenStream {
@@ -105,13 +228,31 @@ impl FieldProperty {
 /// A foreign node, like a foreign key that points to another `Model`
 #[derive(Debug, Clone)]
 pub struct FieldForeignNode {
+  /// the doc comments of the field, as string literals
+  pub docs: Vec<String>,
+
   pub name: Identifier,
   pub foreign_type: Identifier,
 
+  /// whether the field holds an array of foreign nodes, as declared with the
+  /// `field<[ForeignType]>` syntax
+  pub is_array: bool,
+
   pub is_public: bool,
+
+  /// the name of the field in the database if it differs from the rust name
+  pub rename: Option<String>,
+
+  /// the rust type of the field, if any was declared
+  pub data_type: Option<DataType>,
 }
 
 impl FieldForeignNode {
+  /// The identifier of the field in the database
+  pub fn db_name(&self) -> &str {
+    self.rename.as_deref().unwrap_or(self.name.as_ref())
+  }
+
   fn emit_field(&self) -> TokenStream {
     let name = self.name.to_ident();
     let attribute = match self.is_public {
@@ -127,16 +268,19 @@ impl FieldForeignNode {
 
   pub fn emit_initialization(&self) -> TokenStream {
     let name = self.name.to_ident();
-    let name_str: &str = self.name.as_ref();
+    let name_str = self.db_name();
 
-    quote!(#name: SchemaField::new(#name_str, SchemaFieldType::Property))
+    quote!(#name: SchemaField::new(#name_str, SchemaFieldType::Property).with_table(Self::label))
   }
 
   pub fn emit_initialization_with_origin(&self) -> TokenStream {
     let name = self.name.to_ident();
-    let name_str: &str = self.name.as_ref();
+    let name_str = self.db_name();
 
-    quote!(#name: SchemaField::with_origin(#name_str, SchemaFieldType::Property, origin.clone()))
+    quote!(
+      #name: SchemaField::with_origin(#name_str, SchemaFieldType::Property, origin.clone())
+        .with_table(Self::label)
+    )
   }
 
   pub fn emit_foreign_field_function(&self) -> TokenStream {
@@ -159,16 +303,35 @@ impl FieldForeignNode {
       }
     )
   }
+
+  /// The SurrealDB type of the foreign node, a record link to the foreign table
+  /// or an array of them if the field is an array.
+  fn surreal_type(&self) -> String {
+    let record = format!("record<{}>", self.foreign_type);
+    let is_array = self.is_array || self.data_type.as_ref().is_some_and(DataType::is_array);
+
+    match is_array {
+      true => format!("array<{record}>"),
+      false => record,
+    }
+  }
 }
 
 /// A named relation
 #[derive(Debug, Clone)]
 pub struct FieldRelation {
+  /// the doc comments of the field, as string literals
+  pub docs: Vec<String>,
+
   pub name: Identifier,
   pub foreign_type: Identifier,
   pub alias: Identifier,
   pub relation_type: FieldRelationType,
   pub is_public: bool,
+
+  /// the properties stored on the edge itself, as declared with the
+  /// `->edge<{since, strength}>->Foreign` syntax
+  pub properties: Vec<Identifier>,
 }
 
 #[derive(Debug, Clone)]
@@ -215,6 +378,10 @@ impl FieldRelation {
     let foreign_type = self.foreign_type.to_ident();
     let edge = self.edge();
 
+    if !self.properties.is_empty() {
+      return self.emit_edge_field_function();
+    }
+
     quote!(
       pub fn #alias (self) -> #foreign_type <{N + 2}> {
         let origin = self.origin.unwrap_or_else(|| OriginHolder::new([""; N]));
@@ -229,6 +396,97 @@ impl FieldRelation {
     )
   }
 
+  /// Same as [Self::emit_foreign_field_function] but for relations with edge
+  /// properties, the function returns the edge struct that holds the properties
+  /// and derefs to the foreign node.
+  fn emit_edge_field_function(&self) -> TokenStream {
+    let alias = self.alias.to_ident();
+    let foreign_type = self.foreign_type.to_ident();
+    let edge_struct = self.edge_struct_ident();
+    let edge = self.edge();
+    let edge_name = self.name.as_ref();
+
+    let property_assignments = self.properties.iter().map(|property| {
+      let name = property.to_ident();
+      let name_str = property.as_ref();
+
+      quote!(#name: SchemaField::with_origin(#name_str, SchemaFieldType::Property, edge_origin))
+    });
+
+    quote!(
+      pub fn #alias (self) -> #edge_struct <{N + 2}> {
+        let origin = self.origin.unwrap_or_else(|| OriginHolder::new([""; N]));
+        let mut new_nested_origin: [&'static str; N + 2] = [""; N + 2];
+        new_nested_origin[..N].clone_from_slice(&origin.segments);
+
+        new_nested_origin[N] = #edge;
+        new_nested_origin[N + 1] = self.#alias.identifier;
+
+        let mut edge_segments = new_nested_origin;
+        edge_segments[N + 1] = #edge_name;
+        let edge_origin = Some(OriginHolder::new(edge_segments));
+
+        #edge_struct {
+          #(#property_assignments,)*
+          node: #foreign_type::with_origin(OriginHolder::new(new_nested_origin)),
+        }
+      }
+    )
+  }
+
+  /// Emit the struct holding the properties of the edge, if the relation has any.
+  pub fn emit_edge_declaration(&self) -> TokenStream {
+    if self.properties.is_empty() {
+      return quote!();
+    }
+
+    let foreign_type = self.foreign_type.to_ident();
+    let edge_struct = self.edge_struct_ident();
+    let properties = self.properties.iter().map(Identifier::to_ident);
+
+    quote!(
+      /// The properties of the edge, the struct derefs to the foreign node the
+      /// edge points to.
+      pub struct #edge_struct <const N: usize> {
+        #(pub #properties: SchemaField<N>,)*
+        node: #foreign_type<N>,
+      }
+
+      impl<const N: usize> #edge_struct<N> {
+        /// Consume the edge and return the foreign node it points to
+        pub fn node(self) -> #foreign_type<N> {
+          self.node
+        }
+      }
+
+      impl<const N: usize> std::ops::Deref for #edge_struct<N> {
+        type Target = #foreign_type<N>;
+
+        fn deref(&self) -> &Self::Target {
+          &self.node
+        }
+      }
+    )
+  }
+
+  /// The name of the edge struct, the alias in PascalCase followed by `Edge`
+  fn edge_struct_ident(&self) -> impl quote::ToTokens {
+    let pascal_case: String = self
+      .alias
+      .as_ref()
+      .split('_')
+      .filter(|word| !word.is_empty())
+      .map(|word| {
+        let mut chars = word.chars();
+        let first = chars.next().map(|c| c.to_ascii_uppercase());
+
+        first.into_iter().chain(chars).collect::<String>()
+      })
+      .collect();
+
+    quote::format_ident!("{pascal_case}Edge")
+  }
+
   fn edge(&self) -> &'static str {
     match &self.relation_type {
       FieldRelationType::OutgoingEdge => "->",
@@ -1,10 +1,12 @@
 use std::fmt::Debug;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use quote::__private::TokenStream;
 use quote::format_ident;
 use quote::quote;
 
+use super::DataType;
 use super::Field;
 use super::Identifier;
 use super::ModelOptions;
@@ -12,6 +14,10 @@ use super::ModelOptions;
 #[derive(Debug)]
 pub struct Model {
   pub name: Identifier,
+
+  /// the type of the ids of the model, as declared with the `Model<IdType>` syntax
+  pub id_type: Option<DataType>,
+
   pub fields: Vec<Field>,
   pub alias: Option<Identifier>,
   pub options: ModelOptions,
@@ -86,6 +92,68 @@ impl Display for Model {
       }
     };
 
+    let data_declaration = match self.options.data {
+      false => quote! {},
+      true => {
+        let data_name = format_ident!("{}Data", self.name.as_ref());
+        let data_fields: Vec<TokenStream> = self
+          .fields
+          .iter()
+          .map(|field| field.emit_data_field(self.id_type.as_ref()))
+          .collect();
+
+        let into_key_implementation = self.emit_into_key_implementation(&data_name);
+
+        quote! {
+          #[derive(serde::Serialize, serde::Deserialize, Default)]
+          pub struct #data_name {
+            #(#data_fields)*
+          }
+
+          #into_key_implementation
+        }
+      }
+    };
+
+    let define_declaration = match self.options.schema {
+      false => quote! {},
+      true => {
+        let table = self.name.as_ref();
+        let permissions = self.options.emit_permissions_clause();
+        let define_table = format!("DEFINE TABLE {table}{permissions};");
+        let statements: Vec<String> = std::iter::once(define_table)
+          .chain(
+            self
+              .fields
+              .iter()
+              .filter_map(|field| field.emit_define_statement(table)),
+          )
+          .collect();
+        let statements = statements.join(" ");
+
+        quote! {
+          /// The `DEFINE TABLE` & `DEFINE FIELD` statements of the model
+          pub fn define() -> &'static str {
+            #statements
+          }
+        }
+      }
+    };
+
+    let module_declarations: Vec<TokenStream> = self
+      .fields
+      .iter()
+      .map(|field| field.emit_module_declaration())
+      .collect();
+
+    let field_names: Vec<&str> = self.fields.iter().map(Field::column_name).collect();
+    let relation_names: Vec<&str> = self
+      .fields
+      .iter()
+      .filter(|field| !matches!(field, Field::Property(_)))
+      .map(Field::column_name)
+      .collect();
+
     let field_assignments: Vec<TokenStream> = self
       .fields
       .iter()
@@ -126,6 +194,14 @@ impl Display for Model {
         #(#field_foreign_functions)*
       }
 
+      impl #name<0> {
+        /// Build the record link `Table:id` of the model, it serializes into a
+        /// record rather than into a string.
+        pub fn record(id: impl std::fmt::Display) -> Record {
+          Record::new(Self::label, id)
+        }
+      }
+
       impl<const N: usize> std::fmt::Display for #name<N> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
           write!(f, "{}", Self::label)
@@ -138,6 +214,12 @@ impl Display for Model {
         }
       }
 
+      impl<'a, const N: usize> std::convert::From<&#name<N>> for std::borrow::Cow<'a, str> {
+        fn from(_: &#name<N>) -> std::borrow::Cow<'a, str> {
+          std::borrow::Cow::from(#name::<N>::label)
+        }
+      }
+
       impl<const N: usize> std::ops::Deref for #name<N> {
         type Target = str;
 
@@ -169,11 +251,64 @@ impl Display for Model {
         #implementations
 
         #partial_declaration
+        #data_declaration
+        #define_declaration
+        #(#module_declarations)*
 
         pub const model: #name<0> = #name::new();
+
+        /// Build the record id `Table:id` of the model
+        pub fn id(id: &str) -> String {
+          format!("{}:{id}", #name::<0>::label)
+        }
+
+        /// The names of every declared field, in declaration order
+        pub const FIELDS: &[&str] = &[#(#field_names),*];
+
+        /// The names of every foreign node and relation, in declaration order
+        pub const RELATIONS: &[&str] = &[#(#relation_names),*];
       }
     };
 
     write!(f, "{output}")
   }
 }
+
+impl Model {
+  /// Emit the `IntoKey` implementation of the data struct, so it can be used in
+  /// foreign keys. Only emitted when the `foreign` feature is enabled and the
+  /// model declares its id type and has an `id` field.
+  fn emit_into_key_implementation(&self, data_name: &impl quote::ToTokens) -> TokenStream {
+    if !cfg!(feature = "foreign") {
+      return quote!();
+    }
+
+    let Some(id_type) = &self.id_type else {
+      return quote!();
+    };
+
+    let id_field = self.fields.iter().find_map(|field| match field {
+      Field::Property(p) if p.name == *"id" => Some(p),
+      _ => None,
+    });
+
+    let Some(id_field) = id_field else {
+      return quote!();
+    };
+
+    let is_optional = id_field.data_type.as_ref().is_none_or(DataType::is_option);
+    let id_type = TokenStream::from_str(&id_type.to_string()).unwrap();
+    let body = match is_optional {
+      true => quote!(self.id.clone().ok_or(IntoKeyError::MissingId)),
+      false => quote!(Ok(self.id.clone())),
+    };
+
+    quote!(
+      impl IntoKey<#id_type> for #data_name {
+        fn into_key(&self) -> Result<#id_type, IntoKeyError> {
+          #body
+        }
+      }
+    )
+  }
+}
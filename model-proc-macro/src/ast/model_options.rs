@@ -1,12 +1,237 @@
+use super::Identifier;
+
 #[derive(Debug, Default)]
 pub struct ModelOptions {
   pub partial: bool,
+  pub data: bool,
+  pub schema: bool,
+
+  /// the rules of the `permissions(...)` option, used in the `DEFINE TABLE`
+  /// statement generated by the `schema` flag
+  pub permissions: Vec<PermissionRule>,
+}
+
+/// An option of the `with(...)` syntax, either a plain flag like `partial` or
+/// the list of `permissions(...)` rules.
+#[derive(Debug)]
+pub enum ModelOption {
+  Flag(Identifier),
+  Permissions(Identifier, Vec<PermissionRule>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Permission {
+  Full,
+  None,
+  Where(String),
+}
+
+/// Only `full` and `none` are accepted, any other identifier is reported rather
+/// than silently locking the table down.
+impl TryFrom<Identifier> for Permission {
+  type Error = String;
+
+  fn try_from(identifier: Identifier) -> Result<Self, Self::Error> {
+    match identifier.as_ref() {
+      "full" => Ok(Self::Full),
+      "none" => Ok(Self::None),
+      other => Err(format!(
+        "unknown permission `{other}`, expected `full`, `none` or a \"condition\""
+      )),
+    }
+  }
+}
+
+impl std::fmt::Display for Permission {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Permission::Full => write!(f, "FULL"),
+      Permission::None => write!(f, "NONE"),
+      Permission::Where(condition) => write!(f, "WHERE {condition}"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PermissionRule {
+  /// the operation the rule applies to, or `None` if it applies to the whole
+  /// table
+  pub operation: Option<Identifier>,
+  pub permission: Permission,
+}
+
+impl PermissionRule {
+  /// The operations a rule can apply to in a `FOR` clause
+  const OPERATIONS: [&'static str; 4] = ["select", "create", "update", "delete"];
+
+  /// A rule for a single operation, the name of the operation must be one of
+  /// the [PermissionRule::OPERATIONS].
+  pub fn for_operation(operation: Identifier, permission: Permission) -> Result<Self, String> {
+    if !Self::OPERATIONS.contains(&operation.as_ref()) {
+      return Err(format!(
+        "unknown permission operation `{operation}`, expected one of `select`, `create`, `update` or `delete`"
+      ));
+    }
+
+    Ok(Self {
+      operation: Some(operation),
+      permission,
+    })
+  }
+
+  /// A whole-table rule cannot be mixed with other rules, and an operation
+  /// cannot get two rules.
+  fn check_rules(rules: &[PermissionRule]) -> Result<(), String> {
+    let has_table_rule = rules.iter().any(|rule| rule.operation.is_none());
+
+    if has_table_rule && rules.len() > 1 {
+      return Err(
+        "a `full` or `none` permission for the whole table cannot be mixed with other permissions"
+          .to_owned(),
+      );
+    }
+
+    for (index, rule) in rules.iter().enumerate() {
+      let Some(operation) = &rule.operation else {
+        continue;
+      };
+
+      if rules[..index]
+        .iter()
+        .filter_map(|previous| previous.operation.as_ref())
+        .any(|previous| previous.as_ref() == operation.as_ref())
+      {
+        return Err(format!(
+          "the `{operation}` operation has more than one permission"
+        ));
+      }
+    }
+
+    Ok(())
+  }
 }
 
-impl From<Vec<super::Identifier>> for ModelOptions {
-  fn from(flags: Vec<super::Identifier>) -> Self {
-    Self {
-      partial: flags.iter().any(|s| s == "partial"),
+/// The unknown options and the permission rules that would be silently dropped
+/// are reported, as a typo must not result in a table without permissions.
+impl TryFrom<Vec<ModelOption>> for ModelOptions {
+  type Error = String;
+
+  fn try_from(options: Vec<ModelOption>) -> Result<Self, Self::Error> {
+    let mut output = Self::default();
+    let mut has_permissions = false;
+
+    for option in options {
+      match option {
+        ModelOption::Flag(flag) => match flag.as_ref() {
+          "partial" => output.partial = true,
+          "data" => output.data = true,
+          "schema" => output.schema = true,
+          _ => return Err(ModelOptions::unknown_option(&flag)),
+        },
+        ModelOption::Permissions(name, rules) => {
+          if name.as_ref() != "permissions" {
+            return Err(ModelOptions::unknown_option(&name));
+          }
+
+          if has_permissions {
+            return Err("the `permissions(...)` option is declared more than once".to_owned());
+          }
+
+          PermissionRule::check_rules(&rules)?;
+          output.permissions = rules;
+          has_permissions = true;
+        }
+      }
     }
+
+    Ok(output)
   }
 }
+
+impl ModelOptions {
+  fn unknown_option(name: &Identifier) -> String {
+    format!(
+      "unknown model option `{name}`, expected `partial`, `data`, `schema` or `permissions(...)`"
+    )
+  }
+
+  /// Emit the `PERMISSIONS` clause of the `DEFINE TABLE` statement, the
+  /// operations that share the same permission are grouped in a single `FOR`
+  /// clause. An empty string is returned if no permission was declared.
+  pub fn emit_permissions_clause(&self) -> String {
+    // a whole-table rule is always the only rule, see PermissionRule::check_rules
+    if let [PermissionRule {
+      operation: None,
+      permission,
+    }] = self.permissions.as_slice()
+    {
+      return format!(" PERMISSIONS {permission}");
+    }
+
+    let mut groups: Vec<(&Permission, Vec<&str>)> = Vec::new();
+    for rule in &self.permissions {
+      let Some(operation) = &rule.operation else {
+        continue;
+      };
+
+      match groups
+        .iter_mut()
+        .find(|(permission, _)| **permission == rule.permission)
+      {
+        Some((_, operations)) => operations.push(operation.as_ref()),
+        None => groups.push((&rule.permission, vec![operation.as_ref()])),
+      }
+    }
+
+    groups
+      .into_iter()
+      .enumerate()
+      .map(|(index, (permission, operations))| {
+        let keyword = if index == 0 { " PERMISSIONS" } else { "" };
+
+        format!("{keyword} FOR {} {permission}", operations.join(", "))
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+fn parse_options(options: &str) -> Result<ModelOptions, String> {
+  use lalrpop_util::ParseError;
+
+  let model = format!("Account with({options}) {{ id }}");
+
+  match crate::parser::ModelParser::new().parse(&model) {
+    Ok(model) => Ok(model.options),
+    Err(ParseError::User { error }) => Err(error),
+    Err(error) => panic!("{error:?}"),
+  }
+}
+
+#[test]
+fn test_permissions_clause() {
+  let options = parse_options("schema, permissions(full)").unwrap();
+  assert_eq!(options.emit_permissions_clause(), " PERMISSIONS FULL");
+
+  let options =
+    parse_options("schema, permissions(select: full, create: none, update: none)").unwrap();
+  assert_eq!(
+    options.emit_permissions_clause(),
+    " PERMISSIONS FOR select FULL FOR create, update NONE"
+  );
+}
+
+#[test]
+fn test_invalid_options() {
+  let error = |options: &str| parse_options(options).unwrap_err();
+
+  assert!(error("schema, permisions(full)").starts_with("unknown model option `permisions`"));
+  assert!(error("shcema").starts_with("unknown model option `shcema`"));
+  assert!(error("permissions(full), permissions(none)").contains("more than once"));
+  assert!(error("permissions(full, select: none)").contains("cannot be mixed"));
+  assert!(error("permissions(full, none)").contains("cannot be mixed"));
+  assert_eq!(
+    error("permissions(select: full, select: none)"),
+    "the `select` operation has more than one permission"
+  );
+}
@@ -1,9 +1,12 @@
 use std::str::FromStr;
 
+use lalrpop_util::ParseError;
 use proc_macro::TokenStream;
+use quote::quote;
 
 mod ast;
 mod parser;
+mod selectable;
 
 /// The `model` macro allows you to quickly create structs (aka models) with fields
 /// that match the nodes of your database.
@@ -129,8 +132,41 @@ mod parser;
 #[proc_macro]
 pub fn model(input: TokenStream) -> TokenStream {
   let content = input.to_string();
-  let model = parser::ModelParser::new().parse(&content).unwrap();
+  let model = match parser::ModelParser::new().parse(&content) {
+    Ok(model) => model,
+    Err(ParseError::User { error }) => {
+      let output = quote!(compile_error!(#error);).to_string();
+
+      return TokenStream::from_str(&output).unwrap();
+    }
+    Err(error) => panic!("{error:?}"),
+  };
 
   let output = model.to_string();
   TokenStream::from_str(&output).unwrap()
 }
+
+/// Derives the `Selectable` trait, which lists the fields of the struct so a
+/// query can select exactly the fields it deserializes into:
+///
+/// ```rs
+/// #[derive(Deserialize, Selectable)]
+/// struct BookPreview {
+///   title: String,
+///   #[serde(rename = "coverUrl")]
+///   cover_url: String,
+/// }
+///
+/// let query = QueryBuilder::new().select_as::<BookPreview>().from("Book").build();
+///
+/// assert_eq!(query, "SELECT title , coverUrl FROM Book");
+/// ```
+///
+/// The `rename`, `skip` and `skip_deserializing` serde options of the fields and
+/// the `rename_all` option of the struct are taken into account. The trait cannot
+/// be derived for generic structs nor for structs with `#[serde(flatten)]` fields,
+/// as the names of the flattened columns are not known to the macro.
+#[proc_macro_derive(Selectable, attributes(serde))]
+pub fn selectable(input: TokenStream) -> TokenStream {
+  selectable::derive(input)
+}
@@ -0,0 +1,329 @@
+use std::str::FromStr;
+
+use proc_macro::Delimiter;
+use proc_macro::Spacing;
+use proc_macro::TokenStream;
+use proc_macro::TokenTree;
+use quote::format_ident;
+use quote::quote;
+
+/// Emit the `Selectable` implementation of the struct, the input is parsed by
+/// hand as the struct only needs to be split into its fields.
+pub fn derive(input: TokenStream) -> TokenStream {
+  let output = match emit_implementation(input) {
+    Ok(output) => output,
+    Err(message) => quote!(compile_error!(#message);).to_string(),
+  };
+
+  TokenStream::from_str(&output).unwrap()
+}
+
+fn emit_implementation(input: TokenStream) -> Result<String, String> {
+  let mut tokens = input.into_iter();
+  let mut rename_all = None;
+
+  // the container attributes come before the `struct` keyword
+  loop {
+    match tokens.next() {
+      Some(token) if is_ident(&token, "struct") => break,
+      Some(TokenTree::Group(attribute)) if attribute.delimiter() == Delimiter::Bracket => {
+        if let Some(case) = container_rename_all(attribute.stream())? {
+          rename_all = Some(case);
+        }
+      }
+      Some(_) => {}
+      None => return Err("Selectable can only be derived for structs".to_owned()),
+    }
+  }
+
+  let name = match tokens.next() {
+    Some(TokenTree::Ident(name)) => name.to_string(),
+    _ => return Err("Selectable can only be derived for structs".to_owned()),
+  };
+
+  let body = match tokens.next() {
+    Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+    Some(TokenTree::Group(_)) => {
+      return Err("Selectable can only be derived for structs with named fields".to_owned())
+    }
+    _ => return Err("Selectable cannot be derived for generic structs".to_owned()),
+  };
+
+  let mut fields = Vec::new();
+  for field in split_fields(body) {
+    if let Some(name) = field_column_name(field, rename_all)? {
+      fields.push(name);
+    }
+  }
+
+  let name = format_ident!("{name}");
+
+  Ok(
+    quote!(
+      impl surreal_simple_querybuilder::model::Selectable for #name {
+        const FIELDS: &'static [&'static str] = &[#(#fields),*];
+      }
+    )
+    .to_string(),
+  )
+}
+
+/// Split the body of the struct at the commas that separate the fields, the
+/// commas of the generic types like `HashMap<K, V>` are ignored.
+fn split_fields(body: TokenStream) -> Vec<Vec<TokenTree>> {
+  let mut fields = vec![Vec::new()];
+  let mut angle_depth: usize = 0;
+  let mut previous_is_joint_dash = false;
+
+  for token in body {
+    let mut is_joint_dash = false;
+
+    if let TokenTree::Punct(punct) = &token {
+      match punct.as_char() {
+        ',' if angle_depth == 0 => {
+          fields.push(Vec::new());
+          continue;
+        }
+        '<' => angle_depth += 1,
+        // the `>` of a `->` return type does not close a generic type
+        '>' if !previous_is_joint_dash => angle_depth = angle_depth.saturating_sub(1),
+        '-' => is_joint_dash = punct.spacing() == Spacing::Joint,
+        _ => {}
+      }
+    }
+
+    previous_is_joint_dash = is_joint_dash;
+    fields.last_mut().unwrap().push(token);
+  }
+
+  fields
+    .into_iter()
+    .filter(|field| !field.is_empty())
+    .collect()
+}
+
+/// The name of the field in the database: the `rename` of its serde attribute if
+/// any, or its own name with the `rename_all` case of the struct. The fields that
+/// serde skips return `None`.
+fn field_column_name(
+  field: Vec<TokenTree>, rename_all: Option<RenameAll>,
+) -> Result<Option<String>, String> {
+  let mut rename = None;
+  let mut tokens = field.into_iter().peekable();
+
+  loop {
+    let Some(token) = tokens.next() else {
+      return Ok(None);
+    };
+
+    match token {
+      TokenTree::Punct(punct) if punct.as_char() == '#' => {
+        let Some(TokenTree::Group(attribute)) = tokens.next() else {
+          continue;
+        };
+
+        match serde_attribute(attribute.stream()) {
+          SerdeAttribute::Skip => return Ok(None),
+          SerdeAttribute::Rename(name) => rename = Some(name),
+          SerdeAttribute::Flatten => {
+            return Err("Selectable does not support the #[serde(flatten)] fields".to_owned())
+          }
+          SerdeAttribute::None => {}
+        }
+      }
+      TokenTree::Ident(ident) if ident.to_string() == "pub" => {
+        // skip the `(crate)` of a `pub(crate)`
+        if matches!(tokens.peek(), Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis)
+        {
+          tokens.next();
+        }
+      }
+      TokenTree::Ident(ident) => {
+        let name = ident.to_string();
+        let name = name.strip_prefix("r#").map(str::to_owned).unwrap_or(name);
+        let name = match rename_all {
+          Some(case) => case.apply(&name),
+          None => name,
+        };
+
+        return Ok(Some(rename.unwrap_or(name)));
+      }
+      _ => {}
+    }
+  }
+}
+
+enum SerdeAttribute {
+  None,
+  Skip,
+  Flatten,
+  Rename(String),
+}
+
+/// Read the `rename`, `skip`, `skip_deserializing` and `flatten` options of a
+/// `serde(...)` attribute, the other attributes are ignored. Like `rename_all`,
+/// the `rename` option is read in both its `rename = "..."` form and its
+/// `rename(deserialize = "...")` one.
+fn serde_attribute(attribute: TokenStream) -> SerdeAttribute {
+  let mut tokens = attribute.into_iter();
+
+  if !tokens.next().is_some_and(|token| is_ident(&token, "serde")) {
+    return SerdeAttribute::None;
+  }
+
+  let Some(TokenTree::Group(options)) = tokens.next() else {
+    return SerdeAttribute::None;
+  };
+
+  let options: Vec<TokenTree> = options.stream().into_iter().collect();
+  let mut output = SerdeAttribute::None;
+
+  for (index, option) in options.iter().enumerate() {
+    if is_ident(option, "skip") || is_ident(option, "skip_deserializing") {
+      return SerdeAttribute::Skip;
+    }
+
+    if is_ident(option, "flatten") {
+      return SerdeAttribute::Flatten;
+    }
+
+    if is_ident(option, "rename") {
+      let name = match options.get(index + 1) {
+        Some(TokenTree::Group(group)) => deserialize_name(group.stream()),
+        _ => options.get(index + 2).and_then(literal_string),
+      };
+
+      if let Some(name) = name {
+        output = SerdeAttribute::Rename(name);
+      }
+    }
+  }
+
+  output
+}
+
+/// The `rename_all` case of a `serde(...)` container attribute, either in its
+/// `rename_all = "..."` form or in its `rename_all(deserialize = "...")` one.
+fn container_rename_all(attribute: TokenStream) -> Result<Option<RenameAll>, String> {
+  let mut tokens = attribute.into_iter();
+
+  if !tokens.next().is_some_and(|token| is_ident(&token, "serde")) {
+    return Ok(None);
+  }
+
+  let Some(TokenTree::Group(options)) = tokens.next() else {
+    return Ok(None);
+  };
+
+  let options: Vec<TokenTree> = options.stream().into_iter().collect();
+
+  for (index, option) in options.iter().enumerate() {
+    if !is_ident(option, "rename_all") {
+      continue;
+    }
+
+    let case = match options.get(index + 1) {
+      Some(TokenTree::Group(group)) => deserialize_name(group.stream()),
+      _ => options.get(index + 2).and_then(literal_string),
+    };
+
+    return match case {
+      Some(case) => RenameAll::from_serde(&case)
+        .map(Some)
+        .ok_or_else(|| format!("unknown serde rename_all case \"{case}\"")),
+      None => Ok(None),
+    };
+  }
+
+  Ok(None)
+}
+
+/// The `"..."` of the `deserialize = "..."` in a `rename(...)` or a
+/// `rename_all(...)`, the `serialize` name alone does not change the fields that
+/// are deserialized.
+fn deserialize_name(options: TokenStream) -> Option<String> {
+  let options: Vec<TokenTree> = options.into_iter().collect();
+  let index = options
+    .iter()
+    .position(|option| is_ident(option, "deserialize"))?;
+
+  options.get(index + 2).and_then(literal_string)
+}
+
+fn literal_string(token: &TokenTree) -> Option<String> {
+  match token {
+    TokenTree::Literal(literal) => Some(literal.to_string().trim_matches('"').to_owned()),
+    _ => None,
+  }
+}
+
+/// The cases of the serde `rename_all` container attribute, applied to the
+/// snake_case names of the fields the same way serde does it.
+#[derive(Clone, Copy)]
+enum RenameAll {
+  Lower,
+  Upper,
+  Pascal,
+  Camel,
+  Snake,
+  ScreamingSnake,
+  Kebab,
+  ScreamingKebab,
+}
+
+impl RenameAll {
+  fn from_serde(case: &str) -> Option<Self> {
+    let case = match case {
+      "lowercase" => Self::Lower,
+      "UPPERCASE" => Self::Upper,
+      "PascalCase" => Self::Pascal,
+      "camelCase" => Self::Camel,
+      "snake_case" => Self::Snake,
+      "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+      "kebab-case" => Self::Kebab,
+      "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+      _ => return None,
+    };
+
+    Some(case)
+  }
+
+  fn apply(self, field: &str) -> String {
+    match self {
+      Self::Lower | Self::Snake => field.to_owned(),
+      Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+      Self::Pascal => {
+        let mut pascal = String::with_capacity(field.len());
+        let mut capitalize = true;
+
+        for c in field.chars() {
+          match c {
+            '_' => capitalize = true,
+            c if capitalize => {
+              pascal.push(c.to_ascii_uppercase());
+              capitalize = false;
+            }
+            c => pascal.push(c),
+          }
+        }
+
+        pascal
+      }
+      Self::Camel => {
+        let pascal = Self::Pascal.apply(field);
+        let mut chars = pascal.chars();
+
+        match chars.next() {
+          Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+          None => pascal,
+        }
+      }
+      Self::Kebab => field.replace('_', "-"),
+      Self::ScreamingKebab => field.replace('_', "-").to_ascii_uppercase(),
+    }
+  }
+}
+
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+  matches!(token, TokenTree::Ident(ident) if ident.to_string() == name)
+}
@@ -6,11 +6,42 @@ use once_cell::sync::OnceCell;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::record_link::RecordLinks;
 use super::IntoKey;
 use super::IntoKeyError;
 use super::KeySerializeControl;
 use super::LoadedValue;
 
+/// The errors returned by [ForeignKey::try_into_value] when the foreign key is
+/// not holding a loaded value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForeignKeyError {
+  /// The foreign key was holding a key, which usually means the field was not
+  /// fetched.
+  WasKey,
+
+  /// The foreign key was unloaded, which usually means the field was null or
+  /// not selected.
+  WasUnloaded,
+}
+
+impl std::fmt::Display for ForeignKeyError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::WasKey => write!(
+        f,
+        "the foreign key holds a key, the field was probably not fetched"
+      ),
+      Self::WasUnloaded => write!(
+        f,
+        "the foreign key is unloaded, the field was probably null or not selected"
+      ),
+    }
+  }
+}
+
+impl std::error::Error for ForeignKeyError {}
+
 /// Represents foreign data, from a foreign node that may need to be fetched
 /// during the query or else it won't be loaded or it will simply be the ID to a
 /// foreign node.
@@ -76,6 +107,13 @@ use super::LoadedValue;
 /// [`ForeignKey::allow_value_serialize()`] flags the ForeignKey to serialize any
 /// potential value it may hold.
 ///
+/// # ForeignKeys and record links
+/// Keys are serialized as is, so a `Foreign<T>` whose key is the `user:john`
+/// string ends up as a plain string in the database rather than as a record link.
+/// Calling [`ForeignKey::serialize_as_record()`] flags the ForeignKey to
+/// serialize the keys shaped like `table:id` the same way the `Thing` type of
+/// the official client is serialized, so they are stored as record links.
+///
 /// **Note** that if you plan to use `ForeignKey<T, String>` (where the second generic
 /// type is a string), you can use the `Foreign<T>` type in the same module to
 /// shorten the declaration.
@@ -86,6 +124,9 @@ pub struct ForeignKey<V, K> {
 
   #[serde(skip)]
   allow_value_serialize: OnceCell<bool>,
+
+  #[serde(skip)]
+  serialize_as_record: OnceCell<bool>,
 }
 
 impl<V, K> Default for ForeignKey<V, K> {
@@ -93,6 +134,7 @@ impl<V, K> Default for ForeignKey<V, K> {
     Self {
       inner: Default::default(),
       allow_value_serialize: OnceCell::new(),
+      serialize_as_record: OnceCell::new(),
     }
   }
 }
@@ -116,6 +158,7 @@ impl<V: Clone, K: Clone> Clone for ForeignKey<V, K> {
     Self {
       inner: self.inner.clone(),
       allow_value_serialize: self.allow_value_serialize.clone(),
+      serialize_as_record: self.serialize_as_record.clone(),
     }
   }
 }
@@ -161,6 +204,63 @@ impl<V, K> ForeignKey<V, K> {
     self.inner
   }
 
+  /// Flags the ForeignKey to serialize its keys shaped like `table:id` as record
+  /// links rather than plain strings, see the type level documentation. Like the
+  /// [KeySerializeControl] flag, it can only be set once.
+  ///
+  /// ```
+  /// use surreal_simple_querybuilder::foreign_key::*;
+  ///
+  /// let author: Foreign<String> = Foreign::new_key("user:john".to_owned());
+  /// author.serialize_as_record();
+  ///
+  /// assert_eq!(
+  ///   serde_json::to_value(&author).unwrap(),
+  ///   serde_json::json!({ "tb": "user", "id": { "String": "john" } })
+  /// );
+  /// ```
+  pub fn serialize_as_record(&self) {
+    let _ = self.serialize_as_record.set(true);
+  }
+
+  /// Perform a [serialize_as_record()](ForeignKey::serialize_as_record()) and return self
+  pub fn with_record_ser(self) -> Self {
+    self.serialize_as_record();
+
+    self
+  }
+
+  /// Consumes the foreign key to get the loaded value, or the reason why there
+  /// is no value to get.
+  ///
+  /// ```
+  /// use surreal_simple_querybuilder::foreign_key::*;
+  ///
+  /// let author: Foreign<String> = Foreign::new_key("user:john".to_owned());
+  ///
+  /// assert_eq!(author.try_into_value(), Err(ForeignKeyError::WasKey));
+  /// ```
+  pub fn try_into_value(self) -> Result<V, ForeignKeyError> {
+    match self.inner {
+      LoadedValue::Loaded(v) => Ok(v),
+      LoadedValue::Key(_) => Err(ForeignKeyError::WasKey),
+      LoadedValue::Unloaded => Err(ForeignKeyError::WasUnloaded),
+    }
+  }
+
+  /// Consumes the foreign key to get the loaded value, for when it is a
+  /// programmer error for the value not to be loaded, like right after a `FETCH`.
+  ///
+  /// # Panics
+  /// Panics with the supplied `message` if the foreign key is not in the
+  /// `Loaded` state.
+  pub fn expect_value(self, message: &str) -> V {
+    match self.try_into_value() {
+      Ok(v) => v,
+      Err(error) => panic!("{message}: {error}"),
+    }
+  }
+
   /// Take the owned value from this `ForeignKey`, leaving an `Unloaded` value
   /// in its place.
   ///
@@ -225,6 +325,28 @@ impl<V, K> ForeignKey<V, K> {
   {
     self.map(NEWV::from)
   }
+
+  /// Combine two foreign keys into a single one holding a pair, for when two
+  /// foreign keys should only be used if both are in the same state:
+  /// - a loaded pair if both are loaded
+  /// - a key pair if both are keys
+  /// - unloaded in any other case
+  ///
+  /// ```
+  /// use surreal_simple_querybuilder::foreign_key::*;
+  ///
+  /// let author: Foreign<&str> = Foreign::new_value("John");
+  /// let editor: Foreign<&str> = Foreign::new_value("Jean");
+  ///
+  /// assert_eq!(author.zip(editor).value(), Some(&("John", "Jean")));
+  /// ```
+  pub fn zip<V2, K2>(self, other: ForeignKey<V2, K2>) -> ForeignKey<(V, V2), (K, K2)> {
+    match (self.inner, other.inner) {
+      (LoadedValue::Loaded(v), LoadedValue::Loaded(v2)) => ForeignKey::new_value((v, v2)),
+      (LoadedValue::Key(k), LoadedValue::Key(k2)) => ForeignKey::new_key((k, k2)),
+      _ => ForeignKey::new(),
+    }
+  }
 }
 
 impl<V, K> ForeignKey<V, K>
@@ -261,19 +383,40 @@ where
   where
     S: serde::Serializer,
   {
+    let as_record = *self.serialize_as_record.get().unwrap_or(&false);
+
     match (
       &self.inner,
       self.allow_value_serialize.get().unwrap_or(&false),
     ) {
-      (LoadedValue::Loaded(v), false) => v
-        .into_key()
-        .map_err(|intokeyerr| serde::ser::Error::custom(intokeyerr))?
-        .serialize(serializer),
+      (LoadedValue::Loaded(v), false) => {
+        let key = v
+          .into_key()
+          .map_err(|intokeyerr| serde::ser::Error::custom(intokeyerr))?;
+
+        match as_record {
+          true => serialize_record(&key, serializer),
+          false => key.serialize(serializer),
+        }
+      }
+      (LoadedValue::Key(key), _) if as_record => serialize_record(key, serializer),
       (inner, _) => inner.serialize(serializer),
     }
   }
 }
 
+/// Serialize the key through [RecordLinks], the keys that are not strings nor
+/// arrays of strings are serialized as is.
+fn serialize_record<K, S>(key: &K, serializer: S) -> Result<S::Ok, S::Error>
+where
+  K: Serialize,
+  S: serde::Serializer,
+{
+  let key = serde_json::to_value(key).map_err(serde::ser::Error::custom)?;
+
+  RecordLinks(key).serialize(serializer)
+}
+
 impl<V, K> From<LoadedValue<V, K>> for ForeignKey<V, K> {
   fn from(value: LoadedValue<V, K>) -> Self {
     Self {
@@ -293,6 +436,26 @@ where
   }
 }
 
+/// Displays the key of the foreign node, which is obtained through [IntoKey]
+/// when it is loaded. `<unloaded>` is displayed when there is no key, and
+/// `<loaded>` when the loaded value could not provide its key.
+impl<V, K> std::fmt::Display for ForeignKey<V, K>
+where
+  V: IntoKey<K>,
+  K: std::fmt::Display,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.inner {
+      LoadedValue::Key(key) => key.fmt(f),
+      LoadedValue::Loaded(value) => match value.into_key() {
+        Ok(key) => key.fmt(f),
+        Err(_) => write!(f, "<loaded>"),
+      },
+      LoadedValue::Unloaded => write!(f, "<unloaded>"),
+    }
+  }
+}
+
 /// Custom implementation of PartialEq as the allow_value_serialize flag should
 /// NOT be used during the comparison
 impl<V, K> PartialEq for ForeignKey<V, K>
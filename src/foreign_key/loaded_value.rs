@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::ops::Deref;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -35,6 +36,29 @@ impl<V, K> LoadedValue<V, K> {
     }
   }
 
+  /// Converts from `&LoadedValue<V, K>` to `LoadedValue<&V, &K>`, so the data
+  /// can be passed around without moving or cloning it.
+  pub fn as_ref(&self) -> LoadedValue<&V, &K> {
+    match self {
+      Self::Loaded(v) => LoadedValue::Loaded(v),
+      Self::Key(k) => LoadedValue::Key(k),
+      Self::Unloaded => LoadedValue::Unloaded,
+    }
+  }
+
+  /// Same as [LoadedValue::as_ref] but the key is dereferenced, for example a
+  /// `LoadedValue<V, String>` results in a `LoadedValue<&V, &str>`.
+  pub fn as_deref(&self) -> LoadedValue<&V, &K::Target>
+  where
+    K: Deref,
+  {
+    match self {
+      Self::Loaded(v) => LoadedValue::Loaded(v),
+      Self::Key(k) => LoadedValue::Key(k.deref()),
+      Self::Unloaded => LoadedValue::Unloaded,
+    }
+  }
+
   /// Consumes `Self` to get the inner value. If the enum is in any other state
   /// than `Loaded` then a `None` is returned.
   ///
@@ -202,3 +226,19 @@ where
     }
   }
 }
+
+#[test]
+fn test_loaded_value_as_ref() {
+  let loaded: LoadedValue<Vec<u8>, String> = LoadedValue::Loaded(vec![1, 2]);
+  let key: LoadedValue<Vec<u8>, String> = LoadedValue::Key("user:john".to_owned());
+  let unloaded: LoadedValue<Vec<u8>, String> = LoadedValue::Unloaded;
+
+  assert_eq!(loaded.as_ref(), LoadedValue::Loaded(&vec![1, 2]));
+  assert_eq!(key.as_ref(), LoadedValue::Key(&"user:john".to_owned()));
+  assert_eq!(key.as_deref(), LoadedValue::Key("user:john"));
+  assert_eq!(unloaded.as_deref(), LoadedValue::Unloaded);
+
+  // the original values are still usable
+  assert!(loaded.is_loaded());
+  assert!(key.is_key());
+}
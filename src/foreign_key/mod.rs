@@ -2,12 +2,15 @@ mod foreign_key;
 mod into_key;
 mod key_ser_control;
 mod loaded_value;
+mod record_link;
+mod resolve_from;
 
 use loaded_value::*;
 
 pub use foreign_key::*;
 pub use into_key::*;
 pub use key_ser_control::*;
+pub use resolve_from::*;
 
 /// A `ForeignKey` whose `Key` type is set to a `String` by default.
 pub type Foreign<T> = ForeignKey<T, String>;
@@ -0,0 +1,40 @@
+use serde::ser::SerializeSeq;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::record_link::RecordLink;
+
+/// Serializes the strings shaped like `table:id` as record links instead of
+/// plain strings, arrays are serialized recursively and any other value is
+/// serialized as is.
+///
+/// A record link is serialized the same way the `Thing` type of the official
+/// client is, so it is turned back into a record by the client and not into a
+/// `Strand`.
+pub(crate) struct RecordLinks(pub Value);
+
+impl Serialize for RecordLinks {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    match &self.0 {
+      Value::String(key) => match key.split_once(':') {
+        Some((table, id)) if !table.is_empty() && !id.is_empty() => {
+          RecordLink { table, id }.serialize(serializer)
+        }
+        _ => key.serialize(serializer),
+      },
+      Value::Array(keys) => {
+        let mut seq = serializer.serialize_seq(Some(keys.len()))?;
+
+        for key in keys {
+          seq.serialize_element(&RecordLinks(key.clone()))?;
+        }
+
+        seq.end()
+      }
+      value => value.serialize(serializer),
+    }
+  }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::ForeignKey;
+
+pub trait ResolveFrom<K, V> {
+  /// Load the value of the foreign key from a map of already fetched values if
+  /// it is currently holding a key that is present in the map. The foreign key
+  /// is left untouched if it is loaded, unloaded, or if its key is missing from
+  /// the map.
+  ///
+  /// This allows fetching the children of a batch of records in a single query
+  /// and then resolving the foreign keys of every record on the client side,
+  /// rather than issuing one query per record.
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use surreal_simple_querybuilder::foreign_key::*;
+  ///
+  /// let users = HashMap::from([("user:john".to_owned(), "John")]);
+  ///
+  /// let mut author: Foreign<&str> = Foreign::new_key("user:john".to_owned());
+  /// author.resolve_from(&users);
+  ///
+  /// assert_eq!(author.value(), Some(&"John"));
+  /// ```
+  fn resolve_from(&mut self, map: &HashMap<K, V>);
+}
+
+impl<V, K> ResolveFrom<K, V> for ForeignKey<V, K>
+where
+  K: Eq + Hash,
+  V: Clone,
+{
+  fn resolve_from(&mut self, map: &HashMap<K, V>) {
+    let value = self.key().and_then(|key| map.get(key)).cloned();
+
+    if let Some(value) = value {
+      self.set_value(value);
+    }
+  }
+}
+
+/// Blanket implementation for anything that implements ResolveFrom and that is
+/// in a Vec.
+///
+/// This implementation allows calling the ResolveFrom method directly on the
+/// vector itself to resolve every single child element.
+impl<T, K, V> ResolveFrom<K, V> for Vec<T>
+where
+  T: ResolveFrom<K, V>,
+{
+  fn resolve_from(&mut self, map: &HashMap<K, V>) {
+    self.iter_mut().for_each(|item| item.resolve_from(map));
+  }
+}
+
+/// Blanket implementation for anything that implements ResolveFrom and that is
+/// in an Option.
+impl<T, K, V> ResolveFrom<K, V> for Option<T>
+where
+  T: ResolveFrom<K, V>,
+{
+  fn resolve_from(&mut self, map: &HashMap<K, V>) {
+    self.iter_mut().for_each(|item| item.resolve_from(map));
+  }
+}
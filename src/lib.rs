@@ -31,6 +31,10 @@ pub mod types;
 #[cfg(feature = "queries")]
 pub mod queries;
 
+/// The serialization of the record links shared by the foreign keys and the models
+#[cfg(any(feature = "model", feature = "foreign"))]
+mod record_link;
+
 pub mod prelude;
 
 pub use serde_json;
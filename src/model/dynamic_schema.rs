@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::node_builder::escape_ident;
+use crate::prelude::ToNodeBuilder;
+
+/// The runtime counterpart of the schemas generated by the `model` macro, for
+/// the tables that are only known at runtime like user defined tables.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let fields = vec!["name".to_owned(), "age".to_owned()];
+/// let schema = DynamicSchema::new("tenant_user", fields);
+/// let name = schema.field("name").unwrap();
+///
+/// let (query, _) = select("*", &schema, Where((name, "John"))).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM tenant_user WHERE name = $name");
+/// assert!(schema.field("email").is_none());
+/// ```
+///
+/// # Security
+/// As the names usually come from the users, the table and the fields are
+/// escaped using [escape_ident] when they are written in a query, the dots of
+/// the fields are kept to allow nested fields:
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let fields = vec!["first-name".to_owned(), "address.city".to_owned()];
+/// let schema = DynamicSchema::new("tenant-user", fields);
+/// let first_name = schema.field("first-name").unwrap();
+/// let city = schema.field("address.city").unwrap();
+///
+/// let (query, _) = select("*", &schema, Where(((first_name, "John"), (city, "Paris")))).unwrap();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT * FROM `tenant-user` WHERE `first-name` = $first_name AND address.city = $address_city"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicSchema {
+  table: String,
+  fields: Vec<String>,
+}
+
+impl DynamicSchema {
+  pub fn new(table: impl Into<String>, fields: Vec<String>) -> Self {
+    Self {
+      table: table.into(),
+      fields,
+    }
+  }
+
+  pub fn table(&self) -> &str {
+    &self.table
+  }
+
+  /// The names of every field of the schema, like the `FIELDS` const of the
+  /// generated schemas. The names are not escaped, use [DynamicSchema::field] to
+  /// write them in a query.
+  pub fn fields(&self) -> &[String] {
+    &self.fields
+  }
+
+  /// Get the field with the given name, or `None` if the schema has no such
+  /// field.
+  pub fn field(&self, name: &str) -> Option<DynamicField> {
+    self
+      .fields
+      .iter()
+      .find(|field| *field == name)
+      .map(|field| DynamicField(field.clone()))
+  }
+}
+
+impl Display for DynamicSchema {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", escape_ident(&self.table))
+  }
+}
+
+impl<'a> std::convert::From<&'a DynamicSchema> for Cow<'a, str> {
+  fn from(schema: &'a DynamicSchema) -> Self {
+    Cow::Owned(schema.to_string())
+  }
+}
+
+/// A field of a [DynamicSchema], it can be used wherever a `SchemaField` is
+/// expected in the injecters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicField(String);
+
+/// Every segment of the field is escaped on its own so the nested fields keep
+/// their dots.
+impl Display for DynamicField {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (index, segment) in self.0.split('.').enumerate() {
+      if index > 0 {
+        write!(f, ".")?;
+      }
+
+      write!(f, "{}", escape_ident(segment))?;
+    }
+
+    Ok(())
+  }
+}
+
+impl ToNodeBuilder for DynamicField {
+  /// The characters that cannot be used in a parameter name are replaced by
+  /// underscores.
+  fn as_param(&self) -> String {
+    self
+      .0
+      .chars()
+      .map(|c| match c.is_ascii_alphanumeric() {
+        true => c,
+        false => '_',
+      })
+      .collect()
+  }
+}
+
+impl std::ops::Deref for DynamicField {
+  type Target = str;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl std::convert::From<DynamicField> for Cow<'static, str> {
+  fn from(field: DynamicField) -> Self {
+    Cow::Owned(field.to_string())
+  }
+}
+
+impl Serialize for DynamicField {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(&self.0)
+  }
+}
@@ -1,10 +1,87 @@
+//! # Typed partial setters
+//! When a field of a model declares its type, the setter generated for it by
+//! `with(partial)` expects a value of that exact type rather than any serializable
+//! value:
+//! ```
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs)]
+//! mod user {
+//!   use surreal_simple_querybuilder::prelude::*;
+//!
+//!   model!(User { id, pub handle });
+//! }
+//!
+//! mod post {
+//!   use serde::Serialize;
+//!   use surreal_simple_querybuilder::prelude::*;
+//!   use super::user::schema::User;
+//!
+//!   model!(Post with(partial) {
+//!     id,
+//!     pub title: String,
+//!     pub author<User>: String,
+//!   });
+//! }
+//!
+//! fn main() {
+//!   use post::schema::PartialPost;
+//!
+//!   let post = PartialPost::new()
+//!     .title("Hello".to_owned())
+//!     .author("user:john".to_owned());
+//! }
+//! ```
+//!
+//! So passing a value of another type is caught at compile time:
+//! ```compile_fail
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs)]
+//! mod user {
+//!   use surreal_simple_querybuilder::prelude::*;
+//!
+//!   model!(User { id, pub handle });
+//! }
+//!
+//! mod post {
+//!   use serde::Serialize;
+//!   use surreal_simple_querybuilder::prelude::*;
+//!   use super::user::schema::User;
+//!
+//!   model!(Post with(partial) {
+//!     id,
+//!     pub title: String,
+//!     pub author<User>: String,
+//!   });
+//! }
+//!
+//! fn main() {
+//!   use post::schema::PartialPost;
+//!
+//!   let post = PartialPost::new().author(123u8);
+//! }
+//! ```
+
+mod dynamic_schema;
 mod origin_holder;
+mod record;
+#[cfg(feature = "queries")]
+mod relation_node;
 mod schema_field;
+mod selectable;
 mod serialize_error;
 mod serializer;
 
+pub use dynamic_schema::DynamicField;
+pub use dynamic_schema::DynamicSchema;
 pub use origin_holder::OriginHolder;
+pub use record::Record;
+#[cfg(feature = "queries")]
+pub use relation_node::RecordIdValue;
+#[cfg(feature = "queries")]
+pub use relation_node::RelationNode;
 pub use schema_field::SchemaField;
 pub use schema_field::SchemaFieldType;
+pub use selectable::Selectable;
 pub use serialize_error::*;
 pub use serializer::*;
+pub use surreal_simple_querybuilder_proc_macro::Selectable;
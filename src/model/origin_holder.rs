@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-///
+/// Holds the already joined segments of the path that leads to a schema field.
 #[derive(Clone, Copy)]
 pub struct OriginHolder<const N: usize> {
   pub segments: [&'static str; N],
@@ -8,11 +8,7 @@ pub struct OriginHolder<const N: usize> {
 
 impl<const N: usize> Display for OriginHolder<N> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    for segment in self.segments {
-      write!(f, "{segment}")?;
-    }
-
-    Ok(())
+    self.render(f, "")
   }
 }
 
@@ -20,4 +16,45 @@ impl<const N: usize> OriginHolder<N> {
   pub const fn new(segments: [&'static str; N]) -> Self {
     Self { segments }
   }
+
+  /// Write the segments of the origin followed by the `separator` that joins it
+  /// to the field, `.` for the properties and `->`/`<-` for the edges. It is
+  /// what the `SchemaField` display uses, and it allows custom field types to
+  /// render an origin with their own separators.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let origin = OriginHolder::new(["", "author"]);
+  /// let mut path = String::new();
+  /// origin.render(&mut path, "::").unwrap();
+  ///
+  /// assert_eq!(path, "author::");
+  /// ```
+  pub fn render(&self, f: &mut impl std::fmt::Write, separator: &str) -> std::fmt::Result {
+    for segment in self.segments {
+      write!(f, "{segment}")?;
+    }
+
+    write!(f, "{separator}")
+  }
+}
+
+#[test]
+fn test_origin_render() {
+  use crate::prelude::*;
+
+  let origin = OriginHolder::new(["", "author", ".", "profile"]);
+  let field = SchemaField::with_origin("handle", SchemaFieldType::Property, Some(origin));
+
+  assert_eq!(origin.to_string(), "author.profile");
+  assert_eq!(field.to_string(), "author.profile.handle");
+
+  // a custom renderer that writes the path in the javascript style
+  let mut path = "this.".to_owned();
+  origin.render(&mut path, "?.").unwrap();
+  path.push_str(field.identifier);
+
+  assert_eq!(path, "this.author.profile?.handle");
 }
@@ -0,0 +1,71 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::record_link::RecordLink;
+
+/// A record id of a table that serializes into a record link rather than into
+/// a `table:id` string, so it can be bound against `record<table>` fields. It is
+/// serialized the same way the `Thing` type of the official client is.
+///
+/// The models generated by the [model](crate::model) macro offer a `record`
+/// function to build one using the name of the model as the table.
+///
+/// # Example
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// model!(User { id, pub name });
+///
+/// let john = schema::User::record("john");
+///
+/// assert_eq!(john.to_string(), "User:john");
+/// assert_eq!(
+///   serde_json::to_value(&john).unwrap(),
+///   json!({ "tb": "User", "id": { "String": "john" } })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+  table: &'static str,
+  id: String,
+}
+
+impl Record {
+  pub fn new(table: &'static str, id: impl Display) -> Self {
+    Self {
+      table,
+      id: id.to_string(),
+    }
+  }
+
+  pub fn table(&self) -> &'static str {
+    self.table
+  }
+
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+}
+
+impl Display for Record {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}:{}", self.table, self.id)
+  }
+}
+
+impl Serialize for Record {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    RecordLink {
+      table: self.table,
+      id: &self.id,
+    }
+    .serialize(serializer)
+  }
+}
@@ -1,64 +1,186 @@
-use std::fmt::Display;
-use std::ops::Deref;
-
-use super::SchemaFieldType;
-
-pub struct RelationNode<T, Y> {
-  relation_name: &'static str,
-  relation_type: SchemaFieldType,
-
-  /// used when the node type is only needed for its Display impl, it is used by
-  /// the Display implementation.
-  node: T,
-
-  /// used when the node type is needed for its properties, for deeper nesting,
-  /// it is used by the deref implementation.
-  ///
-  /// This is used because relations are either: `->relation->Node` or
-  /// `->relation->Node.nested_property`. In the first case the `Node` would be
-  /// print out by `T::Display` impl whereas the second case needs to be added
-  /// to the origin holder as the `Y::Display` impl is never called since there
-  /// is direct access to the nested properties.
-  nested_node: Y,
+use serde::Serialize;
+
+use super::Record;
+use super::SchemaField;
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::bindings;
+use crate::queries::query;
+use crate::queries::BindingMap;
+use crate::queries::QueryBuildResult;
+use crate::types::ser_to_param_value;
+use crate::types::Set;
+
+/// Builds a `RELATE` statement between two nodes, the ids of the two nodes and
+/// the content of the edge are all bound as parameters:
+/// ```sql
+/// RELATE $relate_from->likes->$relate_to SET since = $since
+/// ```
+///
+/// Unlike the [QueryBuilder::relate()] method which expects the whole
+/// `from->edge->to` segment as a raw string, the `RelationNode` never writes the
+/// ids in the query and can get the name of the edge from the relation fields of
+/// the models, see [RelationNode::from_relation]. SurrealDB only accepts record
+/// ids on both ends of the edge, so the two ids must implement [RecordIdValue]
+/// like the [Record] type or the `Thing` type of the official client do.
+///
+/// The `$relate_from` and `$relate_to` parameters are always named the same, wrap
+/// the node in a [Prefixed](crate::types::Prefixed) to send several `RELATE`
+/// statements in a single query.
+///
+/// # Example
+/// ```
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let john = Record::new("user", "john");
+/// let hello = Record::new("post", "hello");
+/// let (query, params) = RelationNode::new(john, "likes", hello)
+///   .set(json!({ "since": 2023 }))
+///   .build()
+///   .unwrap();
+///
+/// assert_eq!(query, "RELATE $relate_from->likes->$relate_to SET since = $since");
+/// assert_eq!(
+///   params.get("relate_from"),
+///   Some(&json!({ "tb": "user", "id": { "String": "john" } }))
+/// );
+/// assert_eq!(params.get("since"), Some(&json!(2023)));
+/// ```
+pub struct RelationNode<F, T, C = ()> {
+  from: F,
+  edge: &'static str,
+  to: T,
+  content: C,
 }
 
-impl<T, Y> RelationNode<T, Y> {
-  pub fn new(
-    relation_name: &'static str, relation_type: SchemaFieldType, node: T, nested_node: Y,
-  ) -> Self {
+impl<F, T> RelationNode<F, T> {
+  pub fn new(from: F, edge: &'static str, to: T) -> Self {
     Self {
-      relation_name,
-      relation_type,
-      node,
-      nested_node,
+      from,
+      edge,
+      to,
+      content: (),
     }
   }
+
+  /// Same as [RelationNode::new] but the name of the edge is taken from one of
+  /// the relation fields of a model, e.g `account.likes` for a model declaring
+  /// `->likes->Post as likes`.
+  pub fn from_relation<const N: usize>(from: F, relation: SchemaField<N>, to: T) -> Self {
+    Self::new(from, relation.name(), to)
+  }
 }
 
-impl<T, Y> Display for RelationNode<T, Y>
+impl<F, T, C> RelationNode<F, T, C> {
+  /// Sets the content of the edge using the [Set] injecter.
+  pub fn set<S>(self, content: S) -> RelationNode<F, T, Set<S>> {
+    RelationNode {
+      from: self.from,
+      edge: self.edge,
+      to: self.to,
+      content: Set(content),
+    }
+  }
+
+  /// Build the `RELATE` query and its bindings.
+  pub fn build<'a>(self) -> QueryBuildResult<(String, BindingMap)>
+  where
+    Self: QueryBuilderInjecter<'a> + 'a,
+  {
+    let query = query(&self)?;
+    let bindings = bindings(self)?;
+
+    Ok((query, bindings))
+  }
+}
+
+/// The values that serialize into record ids and that can be the ends of a
+/// [RelationNode], a plain `"user:john"` string would be bound as a string.
+pub trait RecordIdValue: Serialize {}
+
+impl RecordIdValue for Record {}
+
+#[cfg(feature = "surrealdb")]
+impl RecordIdValue for surrealdb::sql::Thing {}
+
+impl<T: RecordIdValue> RecordIdValue for &T {}
+
+impl<'a, F, T, C> QueryBuilderInjecter<'a> for RelationNode<F, T, C>
 where
-  T: Display,
+  F: RecordIdValue,
+  T: RecordIdValue,
+  C: QueryBuilderInjecter<'a>,
 {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let joining_segment = match self.relation_type {
-      SchemaFieldType::ForeignRelation => "<-",
-      _ => "->",
-    };
-
-    write!(f, "{}", self.node)
-
-    // write!(
-    //   f,
-    //   "{joining_segment}{}{joining_segment}{}",
-    //   self.relation_name, self.node
-    // )
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let querybuilder = querybuilder.relate(format!("$relate_from->{}->$relate_to", self.edge));
+
+    self.content.inject(querybuilder)
   }
-}
 
-impl<T, Y> Deref for RelationNode<T, Y> {
-  type Target = Y;
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    map.insert("relate_from".to_owned(), ser_to_param_value(self.from)?);
+    map.insert("relate_to".to_owned(), ser_to_param_value(self.to)?);
 
-  fn deref(&self) -> &Self::Target {
-    &self.nested_node
+    self.content.params(map)
   }
 }
+
+#[test]
+fn test_relation_node() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let john = Record::new("user", "john");
+  let hello = Record::new("post", "hello");
+
+  let likes = SchemaField::<0>::new("likes->Post", SchemaFieldType::Relation);
+  let (query, params) = RelationNode::from_relation(&john, likes, &hello)
+    .build()
+    .unwrap();
+
+  assert_eq!(query, "RELATE $relate_from->likes->$relate_to");
+  assert_eq!(
+    params.get("relate_from"),
+    Some(&json!({ "tb": "user", "id": { "String": "john" } }))
+  );
+  assert_eq!(
+    params.get("relate_to"),
+    Some(&json!({ "tb": "post", "id": { "String": "hello" } }))
+  );
+
+  let (query, params) = RelationNode::new(&john, "likes", &hello)
+    .set((Equal(("since", 2023)), Equal(("strength", 5))))
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    query,
+    "RELATE $relate_from->likes->$relate_to SET since = $since , strength = $strength"
+  );
+  assert_eq!(params.get("since"), Some(&json!(2023)));
+  assert_eq!(params.get("strength"), Some(&json!(5)));
+
+  // two RELATE statements in the same query
+  let world = Record::new("post", "world");
+  let statements = (
+    Prefixed("first", RelationNode::new(&john, "likes", &hello)),
+    Sql(";"),
+    Prefixed("second", RelationNode::new(&john, "likes", &world)),
+  );
+  let batch = crate::queries::query(&statements).unwrap();
+  let params = bindings(statements).unwrap();
+
+  assert_eq!(
+    batch,
+    "RELATE $first_relate_from->likes->$first_relate_to ; \
+    RELATE $second_relate_from->likes->$second_relate_to"
+  );
+  assert_eq!(params.len(), 4);
+  assert_eq!(
+    params.get("second_relate_to"),
+    Some(&json!({ "tb": "post", "id": { "String": "world" } }))
+  );
+}
@@ -5,6 +5,8 @@ use serde::Serialize;
 
 use crate::model::OriginHolder;
 use crate::node_builder::ToNodeBuilder;
+#[cfg(feature = "queries")]
+use crate::types::Agg;
 
 #[derive(Debug, Clone, Copy)]
 pub enum SchemaFieldType {
@@ -18,6 +20,9 @@ pub struct SchemaField<const N: usize> {
   pub identifier: &'static str,
   field_type: SchemaFieldType,
   origin_holder: Option<OriginHolder<N>>,
+
+  /// the name of the table of the model the field belongs to, if any
+  table: Option<&'static str>,
 }
 
 impl<const N: usize> SchemaField<N> {
@@ -26,6 +31,7 @@ impl<const N: usize> SchemaField<N> {
       identifier,
       field_type,
       origin_holder: None,
+      table: None,
     }
   }
 
@@ -36,7 +42,123 @@ impl<const N: usize> SchemaField<N> {
       identifier,
       field_type,
       origin_holder: origin,
+      table: None,
+    }
+  }
+
+  /// Set the table of the model the field belongs to, the fields generated by
+  /// the [model] macro always have one.
+  pub const fn with_table(mut self, table: &'static str) -> Self {
+    self.table = Some(table);
+    self
+  }
+
+  /// Build the record id `Table:id` out of the table of the model the field
+  /// belongs to. If the field has no table then only the id is returned.
+  ///
+  /// # Example
+  /// ```
+  /// #![allow(incomplete_features)]
+  /// #![feature(generic_const_exprs)]
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// model!(User {
+  ///   id,
+  ///   pub name
+  /// });
+  ///
+  /// assert_eq!("User:john", schema::model.id.as_record("john"));
+  /// ```
+  pub fn as_record(&self, id: &str) -> String {
+    match self.table {
+      Some(table) => format!("{table}:{id}"),
+      None => id.to_owned(),
+    }
+  }
+
+  /// Build the `count(field) AS alias` projection, meant to be used in the `what`
+  /// part of SELECT statements. The projections of the fields are rendered by the
+  /// [Agg](crate::types::Agg) type, behind the `queries` feature.
+  ///
+  /// # Example
+  /// ```
+  /// #![allow(incomplete_features)]
+  /// #![feature(generic_const_exprs)]
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// model!(Order {
+  ///   id,
+  ///   pub amount
+  /// });
+  ///
+  /// assert_eq!("count(amount) AS orders", schema::model.amount.count_alias("orders"));
+  /// assert_eq!("math::sum(amount) AS total", schema::model.amount.sum_alias("total"));
+  /// ```
+  #[cfg(feature = "queries")]
+  pub fn count_alias(&self, alias: &str) -> String {
+    Agg::count(self, alias).to_string()
+  }
+
+  /// Build the `math::sum(field) AS alias` projection
+  #[cfg(feature = "queries")]
+  pub fn sum_alias(&self, alias: &str) -> String {
+    Agg::sum(self, alias).to_string()
+  }
+
+  /// Build the `math::mean(field) AS alias` projection
+  #[cfg(feature = "queries")]
+  pub fn mean_alias(&self, alias: &str) -> String {
+    Agg::mean(self, alias).to_string()
+  }
+
+  /// Build the `math::min(field) AS alias` projection
+  #[cfg(feature = "queries")]
+  pub fn min_alias(&self, alias: &str) -> String {
+    Agg::min(self, alias).to_string()
+  }
+
+  /// Build the `math::max(field) AS alias` projection
+  #[cfg(feature = "queries")]
+  pub fn max_alias(&self, alias: &str) -> String {
+    Agg::max(self, alias).to_string()
+  }
+
+  /// Repeat the edge of a relation field `hops` times to express multi-hop
+  /// traversals, for example the friends of friends of a user. The origin of the
+  /// field, if any, is only written once before the repeated path. Property
+  /// fields cannot be repeated and are returned as is.
+  ///
+  /// # Example
+  /// ```
+  /// #![allow(incomplete_features)]
+  /// #![feature(generic_const_exprs)]
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// model!(User {
+  ///   id,
+  ///   ->friend->User as friends
+  /// });
+  ///
+  /// assert_eq!("->friend->User->friend->User", schema::model.friends.hops(2));
+  /// ```
+  pub fn hops(&self, hops: usize) -> String {
+    let arrow = match self.field_type {
+      SchemaFieldType::Property => return self.to_string(),
+      SchemaFieldType::Relation => "->",
+      SchemaFieldType::ForeignRelation => "<-",
+    };
+
+    let mut output = match &self.origin_holder {
+      Some(holder) => holder.to_string(),
+      None => String::new(),
+    };
+
+    for _ in 0..hops {
+      output.push_str(arrow);
+      output.push_str(self.identifier);
     }
+
+    output
   }
 
   pub fn from_alias(self, alias: &'static str) -> SchemaField<{ N + 1 }> {
@@ -49,11 +171,12 @@ impl<const N: usize> SchemaField<N> {
     new_origin[1..].clone_from_slice(&origin.segments);
     new_origin[0] = alias;
 
-    SchemaField::<{ N + 1 }>::with_origin(
-      self.identifier,
-      self.field_type,
-      Some(OriginHolder::new(new_origin)),
-    )
+    SchemaField::<{ N + 1 }> {
+      identifier: self.identifier,
+      field_type: self.field_type,
+      origin_holder: Some(OriginHolder::new(new_origin)),
+      table: self.table,
+    }
   }
 
   /// Return the name of the field, and if the field is an edge then return the
@@ -87,20 +210,58 @@ impl<const N: usize> SchemaField<N> {
       }
     }
   }
+
+  /// The segments of the path that leads to the field followed by its
+  /// identifier, meant for debugging the paths of nested fields. The unused
+  /// placeholders of the origin and the `.` that join the foreign nodes are left
+  /// out, while the `->`/`<-` of the relations are kept as their own segments.
+  ///
+  /// # Example
+  /// ```
+  /// #![allow(incomplete_features)]
+  /// #![feature(generic_const_exprs)]
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// model!(User {
+  ///   id,
+  ///   pub name,
+  ///   pub friend<User>,
+  ///   ->likes->User as liked_users
+  /// });
+  ///
+  /// assert_eq!(schema::model.name.segments(), ["name"]);
+  /// assert_eq!(schema::model.friend().friend().name.segments(), ["friend", "friend", "name"]);
+  /// assert_eq!(
+  ///   schema::model.liked_users().name.segments(),
+  ///   ["->", "likes->User", "name"]
+  /// );
+  /// ```
+  pub fn segments(&self) -> Vec<&'static str> {
+    let origin = match &self.origin_holder {
+      Some(holder) => &holder.segments[..],
+      None => &[],
+    };
+
+    origin
+      .iter()
+      .copied()
+      .filter(|segment| !segment.is_empty() && *segment != ".")
+      .chain(std::iter::once(self.identifier))
+      .collect()
+  }
 }
 
 impl<const N: usize> Display for SchemaField<N> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match &self.origin_holder {
       Some(holder) => {
-        write!(f, "{holder}")?;
-
-        match &self.field_type {
-          SchemaFieldType::Property => write!(f, ".")?,
-          SchemaFieldType::Relation => write!(f, "->")?,
-          SchemaFieldType::ForeignRelation => write!(f, "<-")?,
+        let separator = match &self.field_type {
+          SchemaFieldType::Property => ".",
+          SchemaFieldType::Relation => "->",
+          SchemaFieldType::ForeignRelation => "<-",
         };
 
+        holder.render(f, separator)?;
         write!(f, "{}", self.identifier)
       }
       None => {
@@ -118,16 +279,15 @@ impl<const N: usize> Display for SchemaField<N> {
 }
 
 impl<const N: usize> ToNodeBuilder for SchemaField<N> {
-  fn equals_parameterized(&self) -> String {
+  fn as_param(&self) -> String {
     // special case for the schema field as it may include dots, we replace them
-    // by underscores.
-    let key = self
+    // by underscores. The same name must be used in the queries and in the
+    // bindings, so every `*_parameterized` function relies on this one.
+    self
       .to_string()
       .replace(".", "_")
       .replace("->", "_")
-      .replace("<-", "_");
-
-    format!("{key} = ${key}")
+      .replace("<-", "_")
   }
 }
 
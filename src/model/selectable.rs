@@ -0,0 +1,7 @@
+/// The types that list the fields they are deserialized from, so a query can
+/// select exactly these fields rather than `*`. It is usually derived, see the
+/// derive macro of the same name.
+pub trait Selectable {
+  /// The names of the fields in the database, in declaration order
+  const FIELDS: &'static [&'static str];
+}
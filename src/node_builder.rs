@@ -131,6 +131,20 @@ pub trait ToNodeBuilder<T: Display = Self>: Display {
     format!("{self} += ${}", self.as_param())
   }
 
+  /// Take the current string and add `-= $current_string` after it
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let s = "stock".minus_equal_parameterized();
+  ///
+  /// assert_eq!("stock -= $stock", s);
+  /// ```
+  fn minus_equal_parameterized(&self) -> String {
+    format!("{self} -= ${}", self.as_param())
+  }
+
   /// Take the current string and add `> $current_string` after it
   ///
   /// # Example
@@ -159,6 +173,21 @@ pub trait ToNodeBuilder<T: Display = Self>: Display {
     format!("{self} < ${}", self.as_param())
   }
 
+  /// Wrap the current string in a `string::contains()` call whose needle is
+  /// `$current_string`
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let s = "name".substr_contains_parameterized();
+  ///
+  /// assert_eq!("string::contains(name, $name)", s);
+  /// ```
+  fn substr_contains_parameterized(&self) -> String {
+    format!("string::contains({self}, ${})", self.as_param())
+  }
+
   /// Take the current string and add `> value` after it
   ///
   /// # Example
@@ -187,6 +216,20 @@ pub trait ToNodeBuilder<T: Display = Self>: Display {
     format!("{self} += {value}")
   }
 
+  /// Take the current string and add `-= value` after it
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let s = "friends".minus_equal("account:john");
+  ///
+  /// assert_eq!("friends -= account:john", s);
+  /// ```
+  fn minus_equal(&self, value: &str) -> String {
+    format!("{self} -= {value}")
+  }
+
   /// # Example
   /// ```
   /// use surreal_simple_querybuilder::prelude::*;
@@ -274,26 +317,39 @@ pub trait ToNodeBuilder<T: Display = Self>: Display {
   /// assert_eq!("account->manage->(project WHERE name = 'a_cool_project')", s);
   /// ```
   ///
+  /// The last segment starts after the last `->`, `<-` or `.` delimiter:
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let s = "account<-manage<-project_v2".filter("archived = false");
+  ///
+  /// assert_eq!("account<-manage<-(project_v2 WHERE archived = false)", s);
+  /// ```
   fn filter(&self, condition: &str) -> String {
-    // This is a default implementation, but since we need the original string
-    // to iterate over the chars the function does two string allocations.
     let original = self.to_string();
-    let original_size = original.len();
-
-    // this yields the size of the last segment, until a non alphanumeric character
-    // is found.
-    let last_segment_size = original
-      .chars()
-      .rev()
-      .take_while(|c| c.is_alphanumeric())
-      .count();
-
-    let left = &original[..original_size - last_segment_size];
-    let right = &original[original_size - last_segment_size..];
+    let (left, right) = split_last_segment(&original);
 
     format!("{left}({right} WHERE {condition})")
   }
 
+  /// Add the supplied condition in brackets after the current string to filter
+  /// the elements of an array, `$this` refers to the current element. Unlike
+  /// [ToNodeBuilder::filter] the whole path is kept before the brackets.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let tags = "tags".array_filter("$this = 'x'");
+  /// assert_eq!("tags[WHERE $this = 'x']", tags);
+  ///
+  /// let (query, _) = select("*", "Book", Where(Sql(tags.as_str()))).unwrap();
+  /// assert_eq!("SELECT * FROM Book WHERE tags[WHERE $this = 'x']", query);
+  /// ```
+  fn array_filter(&self, condition: &str) -> String {
+    format!("{self}[WHERE {condition}]")
+  }
+
   /// write a comma at the end of the string and append `right` after it.
   ///
   /// # Example
@@ -361,23 +417,72 @@ impl<'a> ToNodeBuilder for &'a str {
   fn filter(&self, condition: &str) -> String {
     // unlike the default implementation of this trait function, the &str impl
     // does only one allocation.
-    let original_size = self.len();
-
-    // this yields the size of the last segment, until a non alphanumeric character
-    // is found.
-    let last_segment_size = self
-      .chars()
-      .rev()
-      .take_while(|c| c.is_alphanumeric())
-      .count();
-
-    let left = &self[..original_size - last_segment_size];
-    let right = &self[original_size - last_segment_size..];
+    let (left, right) = split_last_segment(self);
 
     format!("{left}({right} WHERE {condition})")
   }
 }
 
+/// Escape an identifier, like the name of a table or a field, so it can safely
+/// be written in a query. Identifiers made of letters, digits and underscores
+/// that do not start with a digit are returned as is, any other identifier is
+/// wrapped in backticks and the backslashes and backticks it contains are
+/// escaped with a backslash, like SurrealDB does itself.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// assert_eq!("user", escape_ident("user"));
+/// assert_eq!("`user-archive`", escape_ident("user-archive"));
+/// assert_eq!("`a\\`b`", escape_ident("a`b"));
+/// ```
+pub fn escape_ident(ident: &str) -> String {
+  let is_plain = !ident.is_empty()
+    && !ident.starts_with(|c: char| c.is_ascii_digit())
+    && ident.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+  match is_plain {
+    true => ident.to_owned(),
+    false => {
+      let escaped = ident.replace('\\', "\\\\").replace('`', "\\`");
+
+      format!("`{escaped}`")
+    }
+  }
+}
+
+/// Escape a string literal and wrap it in single quotes so it can safely be
+/// written in a query, the backslashes and the single quotes it contains are
+/// escaped with a backslash.
+///
+/// Binding the value as a parameter should always be preferred when possible.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// assert_eq!("'John'", escape_string("John"));
+/// assert_eq!("'it\\'s'", escape_string("it's"));
+/// ```
+pub fn escape_string(value: &str) -> String {
+  let escaped = value.replace('\\', "\\\\").replace('\'', "\\'");
+
+  format!("'{escaped}'")
+}
+
+/// Splits the path right after its last edge delimiter (`->`, `<-` or `.`), the
+/// second half being the last segment of the path.
+fn split_last_segment(path: &str) -> (&str, &str) {
+  let last_segment_start = ["->", "<-", "."]
+    .iter()
+    .filter_map(|delimiter| path.rfind(delimiter).map(|index| index + delimiter.len()))
+    .max()
+    .unwrap_or(0);
+
+  path.split_at(last_segment_start)
+}
+
 pub trait NodeBuilder<T: Display = Self>: Display {
   /// Draws the start of a relation `->node`
   ///
@@ -391,6 +496,48 @@ pub trait NodeBuilder<T: Display = Self>: Display {
   /// ```
   fn with(&mut self, relation_or_node: &str) -> &mut String;
 
+  /// Draws the end of a relation `<-node`
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let mut s = "project".to_owned();
+  /// (&mut s).from("manage").from("user");
+  ///
+  /// assert_eq!("project<-manage<-user", s);
+  /// ```
+  fn from(&mut self, node: &str) -> &mut String;
+
+  /// Add in front of the current string the given label name as to make a
+  /// string of the following format `LabelName:CurrentString`
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let mut s = "John".to_owned();
+  /// (&mut s).as_named_label("user").with("likes");
+  ///
+  /// assert_eq!("user:John->likes", s);
+  /// ```
+  fn as_named_label(&mut self, label_name: &str) -> &mut String;
+
+  /// Draws the start of a relation to a specific record `->table:id`
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let mut s = String::new();
+  /// (&mut s).with("likes").with_id("user", "john");
+  ///
+  /// assert_eq!("->likes->user:john", s);
+  /// ```
+  fn with_id(&mut self, table: &str, id: &str) -> &mut String {
+    self.with(&format!("{table}:{id}"))
+  }
+
   /// Allows you to pass a lambda that should mutate the current string when the
   /// passed `condition` is `true`. If `condition` is `false` then the `action`
   /// lambda is ignored and the string stays intact.
@@ -459,6 +606,20 @@ impl NodeBuilder for String {
     self
   }
 
+  fn from(&mut self, node: &str) -> &mut String {
+    self.push_str("<-");
+    self.push_str(node);
+
+    self
+  }
+
+  fn as_named_label(&mut self, label_name: &str) -> &mut String {
+    self.insert(0, ':');
+    self.insert_str(0, label_name);
+
+    self
+  }
+
   fn if_then(&mut self, condition: bool, action: fn(&mut Self) -> &mut Self) -> &mut String {
     match condition {
       true => action(self),
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+use surrealdb::method::Query;
+use surrealdb::sql;
+use surrealdb::Connection;
+
+#[cfg(feature = "chrono")]
+use crate::types::DATETIME_TAG;
+use crate::types::DURATION_TAG;
+
+use super::BindingMap;
+
+/// Bind the parameters returned by the premade queries to a query of the official
+/// SurrealDB client.
+///
+/// The record links (`surrealdb::sql::Thing`) do not round-trip through
+/// `serde_json`, they come out as `{ "tb": "user", "id": { "String": "john" } }`
+/// objects. This function turns them back into record links before binding
+/// them, whether they are scalar values, nested in objects or in arrays. The
+/// [SurrealDuration](crate::types::SurrealDuration) and `SurrealDatetime`
+/// wrappers are turned into their SurrealDB counterparts the same way.
+///
+/// # Collisions
+/// The record links are recognized from their shape alone, any object of yours
+/// that is made of exactly a `tb` string and an `id` such as
+/// `{ "String": "john" }` is bound as a record link as well. The durations and
+/// datetimes use keys specific to this crate and do not collide.
+///
+/// # Example
+/// ```rs
+/// let (query, params) = select("*", "book", Where((book.author, author_id)))?;
+/// let books: Vec<IBook> = bind_surreal(DB.query(query), params).await?.take(0)?;
+/// ```
+pub fn bind_surreal<'r, C: Connection>(
+  mut query: Query<'r, C>, params: BindingMap,
+) -> Query<'r, C> {
+  for (key, value) in params {
+    query = query.bind((key, to_surreal_value(value)));
+  }
+
+  query
+}
+
+fn to_surreal_value(value: Value) -> sql::Value {
+  match value {
+    Value::Object(object) if is_record_link(&object) => {
+      match serde_json::from_value::<sql::Thing>(Value::Object(object.clone())) {
+        Ok(thing) => sql::Value::Thing(thing),
+        Err(_) => to_surreal_object(object),
+      }
+    }
+    Value::Object(object) if is_tagged(&object, DURATION_TAG) => {
+      match sql::Duration::try_from(object[DURATION_TAG].as_str().unwrap_or_default()) {
+        Ok(duration) => sql::Value::Duration(duration),
+        Err(_) => to_surreal_object(object),
+      }
+    }
+    #[cfg(feature = "chrono")]
+    Value::Object(object) if is_tagged(&object, DATETIME_TAG) => {
+      match sql::Datetime::try_from(object[DATETIME_TAG].as_str().unwrap_or_default()) {
+        Ok(datetime) => sql::Value::Datetime(datetime),
+        Err(_) => to_surreal_object(object),
+      }
+    }
+    Value::Object(object) => to_surreal_object(object),
+    Value::Array(array) => array
+      .into_iter()
+      .map(to_surreal_value)
+      .collect::<Vec<_>>()
+      .into(),
+    scalar => sql::to_value(scalar).unwrap_or_default(),
+  }
+}
+
+fn to_surreal_object(object: serde_json::Map<String, Value>) -> sql::Value {
+  object
+    .into_iter()
+    .map(|(key, value)| (key, to_surreal_value(value)))
+    .collect::<BTreeMap<_, _>>()
+    .into()
+}
+
+/// Whether the object has the exact shape of a serialized record link, a `tb`
+/// string and an `id` tagged with its kind like `{ "String": "john" }`
+fn is_record_link(object: &serde_json::Map<String, Value>) -> bool {
+  let is_tagged_id = matches!(object.get("id"), Some(Value::Object(id)) if id.len() == 1);
+
+  object.len() == 2 && object.get("tb").is_some_and(Value::is_string) && is_tagged_id
+}
+
+/// Whether the object is a string tagged with `tag`, like `{ "$surreal_duration": "1s" }`
+fn is_tagged(object: &serde_json::Map<String, Value>, tag: &str) -> bool {
+  object.len() == 1 && object.get(tag).is_some_and(Value::is_string)
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use crate::prelude::QueryBuilder;
+use crate::querybuilder::CowSegment;
+
+use super::bindings;
+use super::trace_query;
+use super::BindingMap;
+use super::QueryBuildResult;
+use super::QueryBuilderInjecter;
+
+/// Count the distinct values of `field` in the `from` table, the `component`
+/// can be used to filter the rows that are counted.
+///
+/// # Example
+/// ```rs
+/// let (query, params) = count_distinct("author", "Book", Where(("read", true))).unwrap();
+///
+/// assert_eq!(
+///   "SELECT count(array::distinct(author)) FROM Book WHERE read = $read GROUP ALL",
+///   query
+/// );
+/// ```
+pub fn count_distinct<'a>(
+  field: impl Display, from: impl Into<CowSegment<'a>>,
+  component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
+  let query = QueryBuilder::new()
+    .select(format!("count(array::distinct({field}))"))
+    .from(from)
+    .injecter(&component)
+    .group_all()
+    .build();
+  let bindings = bindings(component)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
+}
+
+#[test]
+fn test_count_distinct() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let (query, params) = count_distinct("author", "Book", Where(("read", true))).unwrap();
+
+  assert_eq!(
+    "SELECT count(array::distinct(author)) FROM Book WHERE read = $read GROUP ALL",
+    query
+  );
+  assert_eq!(params.get("read"), Some(&Value::from(true)));
+
+  let (query, params) = count_distinct("author", "Book", ()).unwrap();
+
+  assert_eq!(
+    "SELECT count(array::distinct(author)) FROM Book GROUP ALL",
+    query
+  );
+  assert!(params.is_empty());
+
+  let table = format!("{}_archive", "Book");
+  let (query, _) = count_distinct("author", table, ()).unwrap();
+
+  assert_eq!(
+    "SELECT count(array::distinct(author)) FROM Book_archive GROUP ALL",
+    query
+  );
+}
@@ -1,11 +1,23 @@
 use crate::prelude::Create;
-use crate::prelude::Set;
 
 use super::bindings;
 use super::query;
+use super::trace_query;
 use super::BindingMap;
+use super::QueryBuildResult;
 use super::QueryBuilderInjecter;
 
+/// Builds a `CREATE` query for the given table, the `component` is usually
+/// either a [Set](crate::types::Set) or a [Content](crate::types::Content):
+/// - `Set` flattens the value into one `field = $field` assignment per field, so
+///   nested objects end up as `author.name = $author_name`.
+/// - `Content` binds the whole value to a single `$content` parameter, which
+///   keeps nested objects and arrays of objects intact.
+///
+/// The [SetOrContent](crate::types::SetOrContent) injecter picks one or the other
+/// depending on whether the value holds nested objects. Note that `SET` merges
+/// the assigned fields into an existing record while `CONTENT` replaces it.
+///
 /// # Example
 /// ```rs
 /// let set = Set(serde_json::json!({ "name": "John", "age": 10 }));
@@ -14,17 +26,24 @@ use super::QueryBuilderInjecter;
 /// assert_eq!("CREATE User SET age = $age , name = $name", query);
 /// assert_eq!(params.get("name"), Some(&"\"John\"".to_owned()));
 /// assert_eq!(params.get("age"), Some(&"10".to_owned()));
+///
+/// let content = Content(serde_json::json!({ "name": "John", "address": { "city": "Paris" } }));
+/// let (query, params) = create("User", content).unwrap();
+///
+/// assert_eq!("CREATE User CONTENT $content", query);
 /// ```
-pub fn create<'a, T>(
-  what: &'static str, component: Set<T>,
-) -> serde_json::Result<(String, BindingMap)>
-where
-  Set<T>: QueryBuilderInjecter<'a> + 'a,
-{
+/// # Security
+/// The `what` parameter is not escaped, if it contains user input then it is
+/// recommended you escape it first using [escape_ident](crate::node_builder::escape_ident).
+pub fn create<'a>(
+  what: &'a str, component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
   let params = (Create(what), component);
   let query = query(&params)?;
   let bindings = bindings(params)?;
 
+  trace_query(&query, &bindings);
+
   Ok((query, bindings))
 }
 
@@ -41,3 +60,34 @@ fn test_create() {
   assert_eq!(params.get("name"), Some(&Value::from("John".to_owned())));
   assert_eq!(params.get("age"), Some(&Value::from(10)));
 }
+
+#[test]
+fn test_create_content() {
+  use crate::prelude::*;
+  use serde::Serialize;
+  use serde_json::json;
+
+  #[derive(Serialize)]
+  struct Address {
+    city: &'static str,
+  }
+
+  #[derive(Serialize)]
+  struct User {
+    name: &'static str,
+    addresses: Vec<Address>,
+  }
+
+  let user = User {
+    name: "John",
+    addresses: vec![Address { city: "Paris" }],
+  };
+  let (query, params) = create("User", Content(user)).unwrap();
+
+  assert_eq!("CREATE User CONTENT $content", query);
+  assert_eq!(params.len(), 1);
+  assert_eq!(
+    params.get("content"),
+    Some(&json!({ "name": "John", "addresses": [{ "city": "Paris" }] }))
+  );
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+
+use crate::prelude::Bind;
+use crate::prelude::Sql;
+
+use super::bindings;
+use super::query;
+use super::trace_query;
+use super::BindingMap;
+use super::QueryBuildResult;
+
+/// Builds a single `INSERT` query that creates one record per object, the whole
+/// array is bound to a single `$rows` parameter:
+/// ```sql
+/// INSERT INTO Book $rows
+/// ```
+///
+/// Unlike a `CREATE` per object, the records are created in a single round trip.
+/// Like the `CREATE` statement the query returns an array of the created records,
+/// in the same order as the objects. The objects that have an `id` field create
+/// the record with that id.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let books = [json!({ "title": "Dune" }), json!({ "title": "Hyperion" })];
+/// let (query, params) = create_many("Book", &books).unwrap();
+///
+/// assert_eq!(query, "INSERT INTO Book $rows");
+/// assert_eq!(params.get("rows"), Some(&json!(books)));
+/// ```
+/// # Security
+/// The `table` parameter is not escaped, if it contains user input then it is
+/// recommended you escape it first using [escape_ident](crate::node_builder::escape_ident).
+pub fn create_many<T: Serialize>(
+  table: &str, objects: &[T],
+) -> QueryBuildResult<(String, BindingMap)> {
+  let params = (
+    Sql("INSERT INTO"),
+    Sql(table),
+    Sql("$rows"),
+    Bind(("rows", objects)),
+  );
+  let query = query(&params)?;
+  let bindings = bindings(params)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
+}
+
+#[test]
+fn test_create_many() {
+  use serde_json::json;
+
+  #[derive(Serialize)]
+  struct Book {
+    title: &'static str,
+    pages: u32,
+  }
+
+  let books = [
+    Book {
+      title: "Dune",
+      pages: 412,
+    },
+    Book {
+      title: "Hyperion",
+      pages: 482,
+    },
+    Book {
+      title: "Foundation",
+      pages: 255,
+    },
+  ];
+
+  let (query, params) = create_many("Book", &books).unwrap();
+
+  assert_eq!(query, "INSERT INTO Book $rows");
+  assert_eq!(params.len(), 1);
+  assert_eq!(
+    params.get("rows"),
+    Some(&json!([
+      { "title": "Dune", "pages": 412 },
+      { "title": "Hyperion", "pages": 482 },
+      { "title": "Foundation", "pages": 255 },
+    ]))
+  );
+}
@@ -2,15 +2,25 @@ use crate::prelude::Delete;
 
 use super::bindings;
 use super::query;
+use super::trace_query;
 use super::BindingMap;
+use super::QueryBuildResult;
 use super::QueryBuilderInjecter;
 
+/// # Security
+/// The `table` parameter is not escaped, if it contains user input then it is
+/// recommended you escape it first using [escape_ident](crate::node_builder::escape_ident).
 pub fn delete<'a, 'b>(
-  table: &'static str, component: impl QueryBuilderInjecter<'a> + 'a,
-) -> serde_json::Result<(String, BindingMap)> {
+  table: &'a str, component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
   let params = (Delete(table), component);
 
-  Ok((query(&params)?, bindings(params)?))
+  let query = query(&params)?;
+  let bindings = bindings(params)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
 }
 
 #[test]
@@ -0,0 +1,113 @@
+use super::BindingMap;
+use super::QueryBuilderInjecter;
+use crate::prelude::QueryBuilder;
+
+/// An object safe version of the [QueryBuilderInjecter] trait, as the `params`
+/// method of the injecters takes them by value and cannot be called on a
+/// `dyn QueryBuilderInjecter`. It is implemented for every injecter, and a
+/// `Box<dyn DynInjecter>` is itself an injecter, so injecters of different
+/// types can be stored in the same collection:
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let mut filters: Vec<Box<dyn DynInjecter>> = Vec::new();
+/// filters.push(Box::new(("name", "John")));
+/// filters.push(Box::new(Greater(("age", 18))));
+///
+/// let (query, _) = select("*", "User", Where(filters)).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM User WHERE name = $name AND age > $age");
+/// ```
+pub trait DynInjecter<'a> {
+  fn dyn_inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a>;
+
+  fn dyn_params(self: Box<Self>, map: &mut BindingMap) -> serde_json::Result<()>;
+}
+
+impl<'a, T> DynInjecter<'a> for T
+where
+  T: QueryBuilderInjecter<'a>,
+{
+  fn dyn_inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    self.inject(querybuilder)
+  }
+
+  fn dyn_params(self: Box<Self>, map: &mut BindingMap) -> serde_json::Result<()> {
+    (*self).params(map)
+  }
+}
+
+impl<'a, 'b> QueryBuilderInjecter<'a> for Box<dyn DynInjecter<'a> + 'b> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    self.as_ref().dyn_inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    self.dyn_params(map)
+  }
+}
+
+/// A type erased injecter, so injecters of any type can be stored in the same
+/// collection and the clauses of a query can be picked at runtime, for example
+/// from the parameters of a request:
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let fetch_author = true;
+/// let mut clauses = vec![BoxedInjecter::new(Where(("published", true)))];
+///
+/// if fetch_author {
+///   clauses.push(BoxedInjecter::new(Fetch(["author"])));
+/// }
+///
+/// let (query, _) = select("*", "Book", clauses).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM Book WHERE published = $published FETCH author");
+/// ```
+pub struct BoxedInjecter<'a>(Box<dyn DynInjecter<'a> + 'a>);
+
+impl<'a> BoxedInjecter<'a> {
+  pub fn new(injecter: impl QueryBuilderInjecter<'a> + 'a) -> Self {
+    Self(Box::new(injecter))
+  }
+}
+
+impl<'a> std::convert::From<Box<dyn DynInjecter<'a> + 'a>> for BoxedInjecter<'a> {
+  fn from(injecter: Box<dyn DynInjecter<'a> + 'a>) -> Self {
+    Self(injecter)
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for BoxedInjecter<'a> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    self.0.inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    self.0.params(map)
+  }
+}
+
+#[test]
+fn test_boxed_injecters() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let clauses = vec![
+    BoxedInjecter::new(Where((("author", "user:john"), Greater(("rating", 3))))),
+    BoxedInjecter::new(OrderBy::desc("rating")),
+    BoxedInjecter::new(Limit(10)),
+    BoxedInjecter::new(Fetch(["author"])),
+  ];
+
+  let (query, params) = select("*", "Book", clauses).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE author = $author AND rating > $rating \
+    ORDER BY rating DESC LIMIT 10 FETCH author"
+  );
+  assert_eq!(params.len(), 2);
+  assert_eq!(params.get("author"), Some(&json!("user:john")));
+  assert_eq!(params.get("rating"), Some(&json!(3)));
+}
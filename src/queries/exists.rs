@@ -0,0 +1,58 @@
+use crate::prelude::QueryBuilder;
+use crate::querybuilder::CowSegment;
+use crate::types::Limit;
+use crate::types::StatementOrder;
+
+use super::bindings;
+use super::trace_query;
+use super::BindingMap;
+use super::QueryBuildResult;
+use super::QueryBuilderInjecter;
+
+/// Select at most one row of the `from` table that matches the `component`, so
+/// checking whether such a row exists is a matter of checking if the response
+/// is empty. The `LIMIT 1` is put in the right place among the clauses of the
+/// component using [StatementOrder], so it still comes before a FETCH.
+///
+/// # Example
+/// ```rs
+/// let (query, params) = exists("User", Where(("email", "john@doe.com"))).unwrap();
+///
+/// assert_eq!("SELECT * FROM User WHERE email = $email LIMIT 1", query);
+///
+/// let users: Vec<User> = DB.query(query).bind(params).await?.take(0)?;
+/// let email_taken = !users.is_empty();
+/// ```
+pub fn exists<'a>(
+  from: impl Into<CowSegment<'a>>, component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
+  let component = StatementOrder((component, Limit("1")));
+  let query = QueryBuilder::new()
+    .select("*")
+    .from(from)
+    .injecter(&component)
+    .build();
+  let bindings = bindings(component)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
+}
+
+#[test]
+fn test_exists() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let (query, params) = exists("User", Where(("email", "john@doe.com"))).unwrap();
+
+  assert_eq!("SELECT * FROM User WHERE email = $email LIMIT 1", query);
+  assert_eq!(params.get("email"), Some(&Value::from("john@doe.com")));
+
+  let (query, _) = exists("Book", (Where(("title", "Lorem")), Fetch(["author"]))).unwrap();
+
+  assert_eq!(
+    "SELECT * FROM Book WHERE title = $title LIMIT 1 FETCH author",
+    query
+  );
+}
@@ -72,6 +72,25 @@ where
   }
 }
 
+/// The tuples inject and bind their elements from left to right, so when two
+/// elements bind the same parameter the last one wins just like it does in the
+/// query.
+impl<'a, I1> QueryBuilderInjecter<'a> for (I1,)
+where
+  I1: QueryBuilderInjecter<'a>,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    self.0.inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    self.0.params(map)
+  }
+}
+
 impl<'a, I1, I2> QueryBuilderInjecter<'a> for (I1, I2)
 where
   I1: QueryBuilderInjecter<'a>,
@@ -85,7 +104,8 @@ where
   where
     Self: Sized,
   {
-    self.1.params(map).and(self.0.params(map))
+    self.0.params(map)?;
+    self.1.params(map)
   }
 }
 
@@ -103,10 +123,9 @@ where
   where
     Self: Sized,
   {
-    self
-      .2
-      .params(map)
-      .and(self.1.params(map).and(self.0.params(map)))
+    self.0.params(map)?;
+    self.1.params(map)?;
+    self.2.params(map)
   }
 }
 
@@ -127,11 +146,24 @@ where
   where
     Self: Sized,
   {
-    self.3.params(map).and(
-      self
-        .2
-        .params(map)
-        .and(self.1.params(map).and(self.0.params(map))),
-    )
+    self.0.params(map)?;
+    self.1.params(map)?;
+    self.2.params(map)?;
+    self.3.params(map)
   }
 }
+
+#[test]
+fn test_tuple_params_order() {
+  use serde_json::json;
+
+  let params = bindings((Bind(("age", 10)), Bind(("age", 20)))).unwrap();
+  assert_eq!(params.get("age"), Some(&json!(20)));
+
+  let params = bindings((Bind(("age", 10)), Bind(("name", "John")), Bind(("age", 30)))).unwrap();
+  assert_eq!(params.get("age"), Some(&json!(30)));
+
+  let filter = Where(("age", 18));
+  let params = bindings((filter, (), Bind(("name", "John")), Bind(("age", 21)))).unwrap();
+  assert_eq!(params.get("age"), Some(&json!(21)));
+}
@@ -2,15 +2,40 @@ use std::collections::HashMap;
 
 use crate::prelude::QueryBuilder;
 
+#[cfg(feature = "surrealdb")]
+mod bind_surreal;
+mod count_distinct;
 mod create;
+mod create_many;
 mod delete;
+mod dyn_injecter;
+mod exists;
 mod impls;
+mod query_cache;
+mod query_error;
 mod select;
+mod select_distinct;
+mod select_options;
+mod select_projections;
+mod static_query;
 mod update;
 
+#[cfg(feature = "surrealdb")]
+pub use bind_surreal::bind_surreal;
+pub use count_distinct::count_distinct;
 pub use create::create;
+pub use create_many::create_many;
 pub use delete::delete;
+pub use dyn_injecter::BoxedInjecter;
+pub use dyn_injecter::DynInjecter;
+pub use exists::exists;
+pub use query_cache::QueryCache;
+pub use query_error::QueryBuildResult;
+pub use query_error::QueryError;
 pub use select::select;
+pub use select_distinct::select_distinct;
+pub use select_options::SelectOptions;
+pub use select_projections::select_projections;
 pub use update::update;
 
 pub type BindingMap = HashMap<String, serde_json::Value>;
@@ -28,9 +53,10 @@ pub trait QueryBuilderInjecter<'a> {
   }
 }
 
-// TODO: this function could maybe be converted to a const fn? Or at least be
-// cached
-pub fn query<'a>(component: &impl QueryBuilderInjecter<'a>) -> serde_json::Result<String> {
+// The injecters cannot be evaluated in a const context, the queries made only
+// of literals can be built at compile time with the `static_query!` macro and
+// the static injecters can be cached using the `QueryCache` type.
+pub fn query<'a>(component: &impl QueryBuilderInjecter<'a>) -> QueryBuildResult<String> {
   let builder = QueryBuilder::new();
   let builder = component.inject(builder);
   let query = builder.build();
@@ -38,11 +64,171 @@ pub fn query<'a>(component: &impl QueryBuilderInjecter<'a>) -> serde_json::Resul
   Ok(query)
 }
 
-pub fn bindings<'a>(
-  component: impl QueryBuilderInjecter<'a> + 'a,
-) -> serde_json::Result<BindingMap> {
+pub fn bindings<'a>(component: impl QueryBuilderInjecter<'a> + 'a) -> QueryBuildResult<BindingMap> {
   let mut params = HashMap::new();
   component.params(&mut params)?;
 
   Ok(params)
 }
+
+/// Same as [bindings] but the parameters are returned sorted by name, so the
+/// output is the same from one run to another as it is needed for snapshot
+/// testing or for comparing logs.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let params = bindings_sorted(Where((("name", "John"), ("age", 10)))).unwrap();
+///
+/// assert_eq!(
+///   params,
+///   vec![("age".to_owned(), json!(10)), ("name".to_owned(), json!("John"))]
+/// );
+/// ```
+pub fn bindings_sorted<'a>(
+  component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<Vec<(String, serde_json::Value)>> {
+  let mut params: Vec<(String, serde_json::Value)> = bindings(component)?.into_iter().collect();
+  params.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+  Ok(params)
+}
+
+/// Same as [bindings] but the names of the parameters are checked to be valid
+/// SurrealDB parameter names, an error is returned for the first one that isn't.
+///
+/// A valid name starts with a letter or an underscore followed by letters,
+/// digits or underscores, and is not one of the parameters predefined by
+/// SurrealDB like `$auth` or `$this`. The names are usually derived from the
+/// field names, so a field named `1st` or `first name` results in a
+/// [QueryError::InvalidIdentifier].
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// assert!(bindings_checked(Where(("first_name", "John"))).is_ok());
+/// assert!(bindings_checked(Where(("first name", "John"))).is_err());
+/// ```
+pub fn bindings_checked<'a>(
+  component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<BindingMap> {
+  let params = bindings(component)?;
+
+  if let Some(name) = params.keys().find(|name| !is_valid_param_name(name)) {
+    return Err(QueryError::InvalidIdentifier(name.to_owned()));
+  }
+
+  Ok(params)
+}
+
+/// The error returned by [merge_bindings] when both maps bind the same parameter
+/// to different values, it holds the name of the parameter.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BindingCollision(pub String);
+
+impl std::fmt::Display for BindingCollision {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "parameter `{}` is bound to different values", self.0)
+  }
+}
+
+impl std::error::Error for BindingCollision {}
+
+/// Merge the bindings of two queries, for example when composing a query out of
+/// sub-queries. Unlike a plain `extend` that silently overwrites the values of
+/// the first map, an error is returned if both maps bind the same parameter to
+/// different values. Binding the same parameter to the same value is allowed.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let a = bindings(Where(("age", 18))).unwrap();
+/// let b = bindings(Where((("age", 18), ("name", "John")))).unwrap();
+/// assert_eq!(merge_bindings(a, b).unwrap().len(), 2);
+///
+/// let a = bindings(Where(("age", 18))).unwrap();
+/// let b = bindings(Where(("age", 21))).unwrap();
+/// assert!(matches!(
+///   merge_bindings(a, b),
+///   Err(QueryError::BindingCollision(BindingCollision(name))) if name == "age"
+/// ));
+/// ```
+pub fn merge_bindings(mut a: BindingMap, b: BindingMap) -> QueryBuildResult<BindingMap> {
+  for (key, value) in b {
+    match a.get(&key) {
+      Some(existing) if *existing != value => {
+        return Err(QueryError::BindingCollision(BindingCollision(key)))
+      }
+      Some(_) => {}
+      None => {
+        a.insert(key, value);
+      }
+    }
+  }
+
+  Ok(a)
+}
+
+/// The parameters that are predefined by SurrealDB and that cannot be bound.
+pub(crate) const RESERVED_PARAMS: &[&str] = &[
+  "auth", "session", "scope", "token", "before", "after", "value", "input", "this", "parent",
+  "event",
+];
+
+/// Rename every `$param` in the segment that is not a reserved parameter using
+/// the `rename` function, which receives the name without its `$`.
+pub(crate) fn rename_params(segment: &str, rename: impl Fn(&str) -> String) -> String {
+  let mut output = String::with_capacity(segment.len());
+  let mut rest = segment;
+
+  while let Some(index) = rest.find('$') {
+    let (before, after) = rest.split_at(index + 1);
+    let name_size = after
+      .find(|c: char| !c.is_alphanumeric() && c != '_')
+      .unwrap_or(after.len());
+    let (name, after_name) = after.split_at(name_size);
+
+    output.push_str(before);
+
+    match !name.is_empty() && !RESERVED_PARAMS.contains(&name) {
+      true => output.push_str(&rename(name)),
+      false => output.push_str(name),
+    };
+
+    rest = after_name;
+  }
+
+  output.push_str(rest);
+  output
+}
+
+/// Returns whether the name can be used as a parameter, `$name`, in a query.
+pub fn is_valid_param_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  let valid_start = chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+
+  valid_start
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    && !RESERVED_PARAMS.contains(&name)
+}
+
+/// Logs the query and the names of its parameters at the `DEBUG` level when the
+/// `tracing` feature is enabled, the values of the parameters are never logged
+/// as they may contain sensitive data. It is a no-op otherwise.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_query(query: &str, bindings: &BindingMap) {
+  let mut params: Vec<&str> = bindings.keys().map(String::as_str).collect();
+  params.sort_unstable();
+
+  tracing::debug!(query, ?params, "built query");
+}
+
+#[cfg(not(feature = "tracing"))]
+#[inline(always)]
+pub(crate) fn trace_query(_query: &str, _bindings: &BindingMap) {}
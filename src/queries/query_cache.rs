@@ -0,0 +1,83 @@
+use std::sync::OnceLock;
+
+use super::query;
+use super::QueryBuildResult;
+use super::QueryBuilderInjecter;
+
+/// Caches the query built from an injecter, so the query of an endpoint that is
+/// called often is only built once. It is meant to be stored in a `static`:
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// static ADULTS: QueryCache = QueryCache::new();
+///
+/// fn adults_query() -> QueryBuildResult<&'static str> {
+///   ADULTS.get_or_build(&(Select("*"), From("user"), Where(Greater(("age", 18)))))
+/// }
+///
+/// assert_eq!(adults_query().unwrap(), "SELECT * FROM user WHERE age > $age");
+/// ```
+///
+/// # Constraints
+/// The cache is not keyed, the first injecter passed to it decides of the query
+/// for the rest of the program. It must only be used for injecters that always
+/// result in the same query, so no `Option`, [When](crate::types::When) or
+/// `serde_json::Value` whose shape depends on runtime values. The bindings are
+/// not cached as they usually change from one call to another, get them using
+/// the [bindings](super::bindings) function.
+pub struct QueryCache(OnceLock<String>);
+
+impl QueryCache {
+  pub const fn new() -> Self {
+    Self(OnceLock::new())
+  }
+
+  /// Returns the cached query, the query is built from the `component` the
+  /// first time only. Nothing is cached if the query fails to build, so the
+  /// error is returned and the next call tries to build it again.
+  pub fn get_or_build<'a>(
+    &self, component: &impl QueryBuilderInjecter<'a>,
+  ) -> QueryBuildResult<&str> {
+    if let Some(query) = self.0.get() {
+      return Ok(query);
+    }
+
+    let built = query(component)?;
+
+    Ok(self.0.get_or_init(|| built))
+  }
+}
+
+impl Default for QueryCache {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[test]
+fn test_query_cache() {
+  use crate::prelude::*;
+
+  static QUERY: QueryCache = QueryCache::new();
+  let component = || {
+    (
+      Select("*"),
+      From("user"),
+      Where((("name", "John"), Greater(("age", 18)))),
+      OrderBy::desc("age"),
+    )
+  };
+
+  let first = QUERY.get_or_build(&component()).unwrap();
+  assert_eq!(
+    first,
+    "SELECT * FROM user WHERE name = $name AND age > $age ORDER BY age DESC"
+  );
+
+  for _ in 0..10 {
+    let query = QUERY.get_or_build(&component()).unwrap();
+    assert!(std::ptr::eq(query, first));
+  }
+}
@@ -0,0 +1,100 @@
+use super::BindingCollision;
+
+/// The error returned by the premade queries and the functions of the `queries`
+/// module.
+#[derive(Debug)]
+pub enum QueryError {
+  /// One of the bound values failed to serialize
+  Serialize(serde_json::Error),
+
+  /// Two injecters bound the same parameter to different values
+  BindingCollision(BindingCollision),
+
+  /// The name of a parameter cannot be used in a query, as reported by
+  /// [bindings_checked](super::bindings_checked). It holds the invalid name.
+  InvalidIdentifier(String),
+}
+
+/// The result type of the functions of the `queries` module
+pub type QueryBuildResult<T> = Result<T, QueryError>;
+
+impl std::fmt::Display for QueryError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      QueryError::Serialize(error) => write!(f, "could not serialize a binding: {error}"),
+      QueryError::BindingCollision(collision) => write!(f, "{collision}"),
+      QueryError::InvalidIdentifier(name) => write!(f, "invalid parameter name `{name}`"),
+    }
+  }
+}
+
+impl std::error::Error for QueryError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      QueryError::Serialize(error) => Some(error),
+      QueryError::BindingCollision(collision) => Some(collision),
+      QueryError::InvalidIdentifier(_) => None,
+    }
+  }
+}
+
+impl From<serde_json::Error> for QueryError {
+  fn from(error: serde_json::Error) -> Self {
+    QueryError::Serialize(error)
+  }
+}
+
+/// Lets the functions that still return a `serde_json::Result` use `?` on the
+/// functions of the `queries` module, as they returned a `serde_json::Result`
+/// before the [QueryError] was introduced. The serialization errors are given
+/// back as is, the other errors are turned into custom errors with the same
+/// message.
+impl From<QueryError> for serde_json::Error {
+  fn from(error: QueryError) -> Self {
+    match error {
+      QueryError::Serialize(error) => error,
+      error => <serde_json::Error as serde::ser::Error>::custom(error),
+    }
+  }
+}
+
+impl From<BindingCollision> for QueryError {
+  fn from(collision: BindingCollision) -> Self {
+    QueryError::BindingCollision(collision)
+  }
+}
+
+#[test]
+fn test_query_error() {
+  use crate::prelude::*;
+
+  let error = bindings_checked(Where(("first name", "John"))).unwrap_err();
+  assert!(matches!(&error, QueryError::InvalidIdentifier(name) if name == "first name"));
+  assert_eq!(error.to_string(), "invalid parameter name `first name`");
+
+  let error = merge_bindings(
+    bindings(Where(("age", 18))).unwrap(),
+    bindings(Where(("age", 21))).unwrap(),
+  )
+  .unwrap_err();
+  assert!(matches!(error, QueryError::BindingCollision(_)));
+
+  let error: QueryError = serde_json::from_str::<u32>("\"John\"").unwrap_err().into();
+  assert!(matches!(error, QueryError::Serialize(_)));
+}
+
+#[test]
+fn test_query_error_into_serde_json_error() {
+  use crate::prelude::*;
+
+  fn legacy() -> serde_json::Result<String> {
+    let (query, _params) = select("*", "user", Where(("name", "John")))?;
+
+    Ok(query)
+  }
+
+  assert_eq!(legacy().unwrap(), "SELECT * FROM user WHERE name = $name");
+
+  let error: serde_json::Error = QueryError::InvalidIdentifier("first name".to_owned()).into();
+  assert_eq!(error.to_string(), "invalid parameter name `first name`");
+}
@@ -1,18 +1,35 @@
+use crate::querybuilder::CowSegment;
 use crate::types::From;
 use crate::types::Select;
 
 use super::bindings;
 use super::query;
+use super::trace_query;
 use super::BindingMap;
+use super::QueryBuildResult;
 use super::QueryBuilderInjecter;
 
+/// # Example
+/// ```rs
+/// let table = format!("{}_archive", "user");
+/// let (query, params) = select("*", table, Where(("name", "John"))).unwrap();
+///
+/// assert_eq!("SELECT * FROM user_archive WHERE name = $name", query);
+/// ```
+/// # Security
+/// The `what` and `from` parameters are not escaped, if they contain user input
+/// then it is recommended you escape them first using
+/// [escape_ident](crate::node_builder::escape_ident).
 pub fn select<'a>(
-  what: &'static str, from: &'static str, component: impl QueryBuilderInjecter<'a> + 'a,
-) -> serde_json::Result<(String, BindingMap)> {
-  let params = (Select(what), From(from), component);
+  what: impl Into<CowSegment<'a>>, from: impl Into<CowSegment<'a>>,
+  component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
+  let params = (Select(what.into()), From(from.into()), component);
   let query = query(&params)?;
   let bindings = bindings(params)?;
 
+  trace_query(&query, &bindings);
+
   Ok((query, bindings))
 }
 
@@ -57,3 +74,18 @@ fn test_select() {
   assert_eq!(params.get("two"), Some(&Value::from(2)));
   assert_eq!(params.get("three"), Some(&Value::from(3)));
 }
+
+#[test]
+fn test_select_owned() {
+  use crate::prelude::*;
+
+  let what = ["name", "age"].join(" , ");
+  let table = format!("{}_archive", "user");
+  let (query, params) = select(what, table, Where(("name", "John"))).unwrap();
+
+  assert_eq!(
+    "SELECT name , age FROM user_archive WHERE name = $name",
+    query
+  );
+  assert!(params.contains_key("name"));
+}
@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use crate::querybuilder::CowSegment;
+use crate::types::Distinct;
+use crate::types::From;
+
+use super::bindings;
+use super::query;
+use super::trace_query;
+use super::BindingMap;
+use super::QueryBuildResult;
+use super::QueryBuilderInjecter;
+
+/// Select the distinct values of `field` in the `from` table using the
+/// [Distinct] injecter.
+///
+/// # Example
+/// ```rs
+/// let (query, params) = select_distinct("tags", "post", ()).unwrap();
+///
+/// assert_eq!("SELECT array::distinct(tags) FROM post", query);
+/// ```
+pub fn select_distinct<'a>(
+  field: impl Display + 'a, from: impl Into<CowSegment<'a>>,
+  component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
+  let params = (Distinct(field), From(from.into()), component);
+  let query = query(&params)?;
+  let bindings = bindings(params)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
+}
+
+#[test]
+fn test_select_distinct() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let (query, params) = select_distinct("tags", "post", ()).unwrap();
+
+  assert_eq!("SELECT array::distinct(tags) FROM post", query);
+  assert!(params.is_empty());
+
+  let (query, params) = select_distinct("tags", "post", Where(("published", true))).unwrap();
+
+  assert_eq!(
+    "SELECT array::distinct(tags) FROM post WHERE published = $published",
+    query
+  );
+  assert_eq!(params.get("published"), Some(&Value::from(true)));
+
+  let table = format!("{}_archive", "post");
+  let (query, _) = select_distinct("tags", table, ()).unwrap();
+
+  assert_eq!("SELECT array::distinct(tags) FROM post_archive", query);
+}
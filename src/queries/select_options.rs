@@ -0,0 +1,122 @@
+use crate::querybuilder::CowSegment;
+use crate::querybuilder::QueryBuilder;
+use crate::types::Limit;
+use crate::types::OrderBy;
+use crate::types::OrderDirection;
+use crate::types::StatementOrder;
+
+use super::select;
+use super::BindingMap;
+use super::QueryBuildResult;
+use super::QueryBuilderInjecter;
+
+/// The defaults shared by the SELECT queries of many list endpoints, like
+/// "ordered by creation date and at most 50 items". The default ORDER BY and
+/// LIMIT are only added when the component passed to [SelectOptions::select]
+/// does not already have its own.
+///
+/// A [Pagination](crate::types::Pagination) that is open-ended only emits a
+/// `START AT` clause, so the default limit still applies to it.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let options = SelectOptions::new()
+///   .default_order(OrderDirection::Desc, "created")
+///   .default_limit(50);
+///
+/// let (query, _) = options.select("*", "Book", Where(("author", "John"))).unwrap();
+/// assert_eq!(query, "SELECT * FROM Book WHERE author = $author ORDER BY created DESC LIMIT 50");
+///
+/// let (query, _) = options.select("*", "Book", Pagination::new_page(1, 10)).unwrap();
+/// assert_eq!(query, "SELECT * FROM Book ORDER BY created DESC LIMIT 10 START AT 10");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SelectOptions<'a> {
+  default_order: Option<(OrderDirection, &'a str)>,
+  default_limit: Option<u64>,
+}
+
+impl<'a> SelectOptions<'a> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The ORDER BY clause used when the component has none
+  pub fn default_order(mut self, direction: OrderDirection, field: &'a str) -> Self {
+    self.default_order = Some((direction, field));
+    self
+  }
+
+  /// The LIMIT clause used when the component has none
+  pub fn default_limit(mut self, limit: u64) -> Self {
+    self.default_limit = Some(limit);
+    self
+  }
+
+  /// Same as [select] but with the default clauses added when the `component`
+  /// does not supply them. The clauses are then put in the order SurrealDB
+  /// expects them using [StatementOrder], so a default ORDER BY still comes
+  /// before a FETCH from the component.
+  pub fn select(
+    &self, what: impl Into<CowSegment<'a>>, from: impl Into<CowSegment<'a>>,
+    component: impl QueryBuilderInjecter<'a> + 'a,
+  ) -> QueryBuildResult<(String, BindingMap)> {
+    let segments = component.inject(QueryBuilder::new()).into_segments();
+    let has_order = StatementOrder::has_clause(&segments, &["ORDER BY"]);
+    let has_limit = StatementOrder::has_clause(&segments, &["LIMIT"]);
+
+    let order = self
+      .default_order
+      .filter(|_| !has_order)
+      .map(|(direction, field)| OrderBy::new(direction, field));
+    let limit = self.default_limit.filter(|_| !has_limit).map(Limit);
+
+    select(what, from, StatementOrder((component, order, limit)))
+  }
+}
+
+#[test]
+fn test_select_options() {
+  use crate::prelude::*;
+
+  let options = SelectOptions::new()
+    .default_order(OrderDirection::Desc, "created")
+    .default_limit(50);
+
+  let (query, _) = options.select("*", "Book", ()).unwrap();
+  assert_eq!(query, "SELECT * FROM Book ORDER BY created DESC LIMIT 50");
+
+  let (query, params) = options
+    .select(
+      "*",
+      "Book",
+      (Fetch(["author"]), OrderBy::asc("title"), Limit(10)),
+    )
+    .unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM Book ORDER BY title ASC LIMIT 10 FETCH author"
+  );
+  assert!(params.is_empty());
+
+  let (query, _) = options
+    .select("*", "Book", (Where(("title", "Dune")), Fetch(["author"])))
+    .unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE title = $title ORDER BY created DESC LIMIT 50 FETCH author"
+  );
+
+  // the keywords of a subquery are not the clauses of the select
+  let subquery = Sql("author IN ( SELECT * FROM Account ORDER BY age LIMIT 5 )");
+  let (query, _) = options.select("*", "Book", Where(subquery)).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE author IN ( SELECT * FROM Account ORDER BY age LIMIT 5 ) ORDER BY created DESC LIMIT 50"
+  );
+
+  let (query, _) = SelectOptions::new().select("*", "Book", ()).unwrap();
+  assert_eq!(query, "SELECT * FROM Book");
+}
@@ -0,0 +1,72 @@
+use crate::prelude::QueryBuilder;
+use crate::querybuilder::CowSegment;
+use crate::types::Agg;
+
+use super::bindings;
+use super::trace_query;
+use super::BindingMap;
+use super::QueryBuildResult;
+use super::QueryBuilderInjecter;
+
+/// Select the given aggregate projections from the `from` table, the `component`
+/// can be used to filter & group the rows.
+///
+/// # Example
+/// ```rs
+/// let projections = [Agg::sum("amount", "total"), Agg::count("id", "orders")];
+/// let (query, params) = select_projections(&projections, "order", GroupBy(["customer"])).unwrap();
+///
+/// assert_eq!(
+///   "SELECT math::sum(amount) AS total , count(id) AS orders FROM order GROUP BY customer",
+///   query
+/// );
+/// ```
+pub fn select_projections<'a>(
+  projections: &[Agg], from: impl Into<CowSegment<'a>>,
+  component: impl QueryBuilderInjecter<'a> + 'a,
+) -> QueryBuildResult<(String, BindingMap)> {
+  let projections: Vec<String> = projections.iter().map(Agg::to_string).collect();
+  let query = QueryBuilder::new()
+    .select(projections.join(" , "))
+    .from(from)
+    .injecter(&component)
+    .build();
+  let bindings = bindings(component)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
+}
+
+#[test]
+fn test_select_projections() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let projections = [
+    Agg::sum("amount", "total"),
+    Agg::mean("amount", "average"),
+    Agg::min("amount", "lowest"),
+    Agg::max("amount", "highest"),
+    Agg::count("id", "orders"),
+  ];
+  let (query, params) = select_projections(
+    &projections,
+    "order",
+    (Where(("paid", true)), GroupBy(["customer"])),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT math::sum(amount) AS total , math::mean(amount) AS average , \
+    math::min(amount) AS lowest , math::max(amount) AS highest , count(id) AS orders \
+    FROM order WHERE paid = $paid GROUP BY customer"
+  );
+  assert_eq!(params.get("paid"), Some(&Value::from(true)));
+
+  let table = format!("{}_archive", "order");
+  let (query, _) = select_projections(&[Agg::count("id", "orders")], table, ()).unwrap();
+
+  assert_eq!(query, "SELECT count(id) AS orders FROM order_archive");
+}
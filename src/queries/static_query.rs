@@ -0,0 +1,73 @@
+/// Build a query out of string literals at compile time, so no work happens at
+/// runtime for the queries that never change. The clauses must be passed in the
+/// same order as below, only `select` and `from` are required:
+/// ```
+/// use surreal_simple_querybuilder::static_query;
+///
+/// const QUERY: &str = static_query!(
+///   select: "*",
+///   from: "User",
+///   where: "age > $age",
+///   order_by: "age DESC",
+///   limit: 10,
+/// );
+///
+/// assert_eq!(QUERY, "SELECT * FROM User WHERE age > $age ORDER BY age DESC LIMIT 10");
+/// ```
+///
+/// # Constraints
+/// Every clause must be a literal, so nothing is parameterized by the macro
+/// itself. The query can still reference parameters like the `$age` above, whose
+/// values are then bound separately, for example with `bindings(Bind(("age", 18)))`.
+/// For the queries that are built at runtime but never change, refer to the
+/// [QueryCache](crate::queries::QueryCache) type.
+#[macro_export]
+macro_rules! static_query {
+  (
+    select: $what:literal,
+    from: $from:literal
+    $(, where: $condition:literal)?
+    $(, order_by: $order:literal)?
+    $(, limit: $limit:literal)?
+    $(, fetch: $fetch:literal)?
+    $(,)?
+  ) => {
+    concat!(
+      "SELECT ", $what, " FROM ", $from
+      $(, " WHERE ", $condition)?
+      $(, " ORDER BY ", $order)?
+      $(, " LIMIT ", $limit)?
+      $(, " FETCH ", $fetch)?
+    )
+  };
+}
+
+#[test]
+fn test_static_query() {
+  use crate::prelude::QueryBuilder;
+
+  const SELECT_ALL: &str = static_query!(select: "*", from: "User");
+  static ADULTS: &str = static_query!(
+    select: "name , age",
+    from: "User",
+    where: "age >= $age",
+    limit: 10,
+    fetch: "friends"
+  );
+
+  assert_eq!(SELECT_ALL, "SELECT * FROM User");
+  assert_eq!(
+    ADULTS,
+    "SELECT name , age FROM User WHERE age >= $age LIMIT 10 FETCH friends"
+  );
+
+  let dynamic = QueryBuilder::new()
+    .select("name , age")
+    .from("User")
+    .filter("age >= $age")
+    .limit("10")
+    .fetch("friends")
+    .build();
+
+  assert_eq!(ADULTS, dynamic);
+}
@@ -2,7 +2,9 @@ use crate::prelude::Update;
 
 use super::bindings;
 use super::query;
+use super::trace_query;
 use super::BindingMap;
+use super::QueryBuildResult;
 use super::QueryBuilderInjecter;
 
 /// # Example
@@ -19,13 +21,18 @@ use super::QueryBuilderInjecter;
 /// ```
 /// # Security
 /// The `table` parameter is not escaped, if it contains user input then it is
-/// recommended you escape the data manually first.
+/// recommended you escape it first using [escape_ident](crate::node_builder::escape_ident).
 pub fn update<'a, 'b>(
   table: &'a str, component: impl QueryBuilderInjecter<'a> + 'a,
-) -> serde_json::Result<(String, BindingMap)> {
+) -> QueryBuildResult<(String, BindingMap)> {
   let params = (Update(table), component);
 
-  Ok((query(&params)?, bindings(params)?))
+  let query = query(&params)?;
+  let bindings = bindings(params)?;
+
+  trace_query(&query, &bindings);
+
+  Ok((query, bindings))
 }
 
 #[test]
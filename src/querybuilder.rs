@@ -8,15 +8,49 @@ use crate::prelude::SqlSerializeResult;
 
 pub type CowSegment<'a> = Cow<'a, str>;
 
+/// The clause keywords that always expect at least one segment after them. Used
+/// by [QueryBuilder::build_validated] to detect empty clauses.
+const CLAUSE_KEYWORDS: &[&str] = &[
+  "SELECT", "FROM", "WHERE", "SET", "CREATE", "UPDATE", "DELETE", "RELATE", "CONTENT", "FETCH",
+  "GROUP BY", "ORDER BY", "LIMIT", "START AT",
+];
+
+/// The errors that can be returned by [QueryBuilder::build_validated] when the
+/// segments do not form a structurally valid query.
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuildError {
+  /// An opening or closing parenthesis has no matching counterpart.
+  UnbalancedParentheses,
+
+  /// The clause keyword is immediately followed by another clause keyword, or
+  /// ends the query, e.g `SET WHERE ...`
+  EmptyClause(String),
+}
+
+impl std::fmt::Display for BuildError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      BuildError::UnbalancedParentheses => write!(f, "unbalanced parentheses in query"),
+      BuildError::EmptyClause(keyword) => write!(f, "empty {keyword} clause in query"),
+    }
+  }
+}
+
+impl std::error::Error for BuildError {}
+
 #[derive(Debug)]
 enum QueryBuilderInsertExceptions {
   None,
   AndOr,
+
+  /// the previous segment starts a group of conditions (`WHERE` or `(`), a AND
+  /// or OR connector right after it would have no left operand.
+  ConditionStart,
 }
 
 pub struct QueryBuilder<'a> {
   segments: Vec<CowSegment<'a>>,
-  parameters: HashMap<&'a str, &'a str>,
+  parameters: HashMap<&'a str, CowSegment<'a>>,
 
   /// this private enum is used as a marker for the next segment that will be
   /// inserted to detect if it should be cancelled/replaced or not.
@@ -172,6 +206,55 @@ impl<'a> QueryBuilder<'a> {
     self
   }
 
+  /// Selects exactly the fields of the [Selectable](crate::model::Selectable)
+  /// type, so the projection cannot drift from the type the records are
+  /// deserialized into. The names that are not plain identifiers, like the
+  /// kebab-case ones, are escaped using [escape_ident](crate::node_builder::escape_ident).
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// #[derive(Selectable)]
+  /// struct BookPreview {
+  ///   title: String,
+  ///   pages: u32,
+  /// }
+  ///
+  /// let query = QueryBuilder::new().select_as::<BookPreview>().from("Book").build();
+  ///
+  /// assert_eq!(query, "SELECT title , pages FROM Book");
+  /// ```
+  #[cfg(feature = "model")]
+  pub fn select_as<T: crate::model::Selectable>(self) -> Self {
+    self.select_iter(
+      T::FIELDS
+        .iter()
+        .map(|field| crate::node_builder::escape_ident(field)),
+    )
+  }
+
+  /// Same as [QueryBuilder::select_many] but accepts any iterator, so field lists
+  /// built at runtime like a `Vec<String>` can be passed.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let fields: Vec<String> = ["id", "name"].iter().map(|f| f.to_string()).collect();
+  /// let query = QueryBuilder::new().select_iter(fields).from("user").build();
+  ///
+  /// assert_eq!(query, "SELECT id , name FROM user")
+  /// ```
+  pub fn select_iter<T: Into<CowSegment<'a>>>(
+    mut self, nodes: impl IntoIterator<Item = T>,
+  ) -> Self {
+    self.add_segment("SELECT");
+    self.join_segments_iter(",", nodes);
+
+    self
+  }
+
   /// Adds the supplied query with a comma in front of it
   ///
   /// # Example
@@ -228,6 +311,61 @@ impl<'a> QueryBuilder<'a> {
     self
   }
 
+  /// Same as [QueryBuilder::join_segments] but for any iterator and without any
+  /// prefix or suffix.
+  fn join_segments_iter<T: Into<CowSegment<'a>>>(
+    &mut self, seperator: &'a str, segments: impl IntoIterator<Item = T>,
+  ) -> &mut Self {
+    for (index, segment) in segments.into_iter().enumerate() {
+      if index > 0 {
+        self.add_segment(seperator);
+      }
+
+      self.add_segment(segment);
+    }
+
+    self
+  }
+
+  /// Adds the `ONLY` keyword right before the target of the statement so that
+  /// a single record is returned instead of an array. Since the keyword sits
+  /// between the `FROM` (or `CREATE`, `UPDATE`, `DELETE`, `RELATE`) keyword and
+  /// the target, it is inserted after the last of these keywords no matter when
+  /// the method is called, as long as it is called after it. Nothing is added if
+  /// none of these keywords was written yet, as a bare `ONLY` would only result
+  /// in an invalid query.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .select("*")
+  ///   .from("user:john")
+  ///   .only()
+  ///   .build();
+  ///
+  /// assert_eq!(query, "SELECT * FROM ONLY user:john");
+  /// ```
+  pub fn only(mut self) -> Self {
+    let target = self.segments.iter().rposition(|s| {
+      matches!(
+        s.as_ref(),
+        "FROM" | "CREATE" | "UPDATE" | "DELETE" | "RELATE"
+      )
+    });
+
+    if let Some(index) = target {
+      let is_only = self.segments.get(index + 1).is_some_and(|s| s == "ONLY");
+
+      if !is_only {
+        self.segments.insert(index + 1, CowSegment::from("ONLY"));
+      }
+    }
+
+    self
+  }
+
   /// Starts a WHERE clause.
   ///
   /// # Example
@@ -333,6 +471,15 @@ impl<'a> QueryBuilder<'a> {
     self
   }
 
+  /// Same as [QueryBuilder::set_many] but accepts any iterator, so assignments
+  /// built at runtime like a `Vec<String>` can be passed.
+  pub fn set_iter<T: Into<CowSegment<'a>>>(mut self, updates: impl IntoIterator<Item = T>) -> Self {
+    self.add_segment("SET");
+    self.join_segments_iter(",", updates);
+
+    self
+  }
+
   /// Starts a FETCH clause,
   ///
   /// # Example
@@ -363,12 +510,42 @@ impl<'a> QueryBuilder<'a> {
   ///
   /// assert_eq!(query, "FETCH author , projects");
   /// ```
-  pub fn fetch_many<T: Into<CowSegment<'a>>>(mut self, fields: &[T]) -> Self
+  ///
+  /// The fields that appear more than once are only fetched once, at the
+  /// position of their first occurence:
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .fetch_many(&["author", "projects", "author"])
+  ///   .build();
+  ///
+  /// assert_eq!(query, "FETCH author , projects");
+  /// ```
+  pub fn fetch_many<T: Into<CowSegment<'a>>>(self, fields: &[T]) -> Self
   where
     T: Copy,
   {
+    self.fetch_iter(fields.iter().copied())
+  }
+
+  /// Same as [QueryBuilder::fetch_many] but accepts any iterator, so field lists
+  /// built at runtime like a `Vec<String>` can be passed.
+  pub fn fetch_iter<T: Into<CowSegment<'a>>>(
+    mut self, fields: impl IntoIterator<Item = T>,
+  ) -> Self {
+    let mut unique_fields: Vec<CowSegment<'a>> = Vec::new();
+
+    for field in fields {
+      let field = field.into();
+
+      if !unique_fields.contains(&field) {
+        unique_fields.push(field);
+      }
+    }
+
     self.add_segment("FETCH");
-    self.join_segments(",", "", fields, "");
+    self.join_segments_iter(",", unique_fields);
 
     self
   }
@@ -413,6 +590,37 @@ impl<'a> QueryBuilder<'a> {
     self
   }
 
+  /// Same as [QueryBuilder::group_by_many] but accepts any iterator, so field
+  /// lists built at runtime like a `Vec<String>` can be passed.
+  pub fn group_by_iter<T: Into<CowSegment<'a>>>(
+    mut self, fields: impl IntoIterator<Item = T>,
+  ) -> Self {
+    self.add_segment("GROUP BY");
+    self.join_segments_iter(",", fields);
+
+    self
+  }
+
+  /// Adds a GROUP ALL clause,
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .select("count()")
+  ///   .from("author")
+  ///   .group_all()
+  ///   .build();
+  ///
+  /// assert_eq!(query, "SELECT count() FROM author GROUP ALL");
+  /// ```
+  pub fn group_all(mut self) -> Self {
+    self.add_segment("GROUP ALL");
+
+    self
+  }
+
   /// Starts a ORDER BY ASC clause,
   ///
   /// # Example
@@ -552,11 +760,44 @@ impl<'a> QueryBuilder<'a> {
   ///
   /// assert_eq!(query, "SELECT * FROM user WHERE name contains 'John' AND ( name contains 'Doe' OR name contains 'Eod' )");
   /// ```
-  pub fn and_group<F, T: Into<CowSegment<'a>>>(mut self, first_condition: T, action: F) -> Self
+  pub fn and_group<F, T>(self, first_condition: T, action: F) -> Self
   where
-    F: Fn(Self) -> Self,
+    T: Into<CowSegment<'a>>,
+    F: FnOnce(Self) -> Self,
+  {
+    self.group("AND", first_condition, action)
+  }
+
+  /// Same as [QueryBuilder::and_group] but the group is preceded by an OR. The
+  /// groups can be nested by opening a new one in the `action` closure:
+  /// ```sql
+  /// WHERE a AND ( b OR ( c AND d ) )
+  /// ```
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .filter("a")
+  ///   .and_group("b", |q| q.or_group("c", |q| q.and("d")))
+  ///   .build();
+  ///
+  /// assert_eq!(query, "WHERE a AND ( b OR ( c AND d ) )");
+  /// ```
+  pub fn or_group<F, T>(self, first_condition: T, action: F) -> Self
+  where
+    T: Into<CowSegment<'a>>,
+    F: FnOnce(Self) -> Self,
   {
-    self.add_segment_p("AND", "(");
+    self.group("OR", first_condition, action)
+  }
+
+  fn group<F, T>(mut self, operator: &'a str, first_condition: T, action: F) -> Self
+  where
+    T: Into<CowSegment<'a>>,
+    F: FnOnce(Self) -> Self,
+  {
+    self.add_segment_p(operator, "(");
     self.add_segment(first_condition);
     let mut output = action(self);
     output.add_segment(")");
@@ -609,6 +850,8 @@ impl<'a> QueryBuilder<'a> {
         self.add_segment(",");
         self.segments.push(segment);
       }
+
+      self.insert_exceptions = QueryBuilderInsertExceptions::None;
     }
 
     self
@@ -637,6 +880,7 @@ impl<'a> QueryBuilder<'a> {
     for (index, segment) in other.segments.into_iter().enumerate() {
       if index <= 0 {
         self.segments.push(segment);
+        self.insert_exceptions = QueryBuilderInsertExceptions::None;
       } else {
         self = self.and(segment);
       }
@@ -676,6 +920,75 @@ impl<'a> QueryBuilder<'a> {
     self
   }
 
+  /// Same as [QueryBuilder::ands] but the conditions are surrounded by parenthesis
+  /// and pushed as a single segment, which means the group can itself be nested
+  /// in another `ands`, `ors`, `ands_group` or `ors_group` queue. Nothing is
+  /// pushed if the `action` closure adds no segment.
+  ///
+  /// Unlike [QueryBuilder::and_group] no connector is written before the group.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .select("*")
+  ///   .from("user")
+  ///   .filter("")
+  ///   .ands_group(|q| q
+  ///     .raw("name = $name")
+  ///     .ors_group(|q| q
+  ///       .raw("age < 18")
+  ///       .ands_group(|q| q.raw("age > 65").raw("retired = true"))
+  ///     )
+  ///   )
+  ///   .build();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT * FROM user WHERE ( name = $name AND ( age < 18 OR ( age > 65 AND retired = true ) ) )"
+  /// );
+  /// ```
+  pub fn ands_group<F>(mut self, action: F) -> Self
+  where
+    F: Fn(Self) -> Self,
+  {
+    let group = QueryBuilder::new().ands(action);
+
+    if !group.is_empty() {
+      self.add_segment(format!("( {} )", group.build()));
+    }
+
+    self
+  }
+
+  /// Same as [QueryBuilder::ands_group] but the conditions are separated by `OR`s.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .filter("name = $name")
+  ///   .and("")
+  ///   .ors_group(|q| q.raw("age < 18").raw("age > 65"))
+  ///   .build();
+  ///
+  /// assert_eq!(query, "WHERE name = $name AND ( age < 18 OR age > 65 )");
+  /// ```
+  pub fn ors_group<F>(mut self, action: F) -> Self
+  where
+    F: Fn(Self) -> Self,
+  {
+    let group = QueryBuilder::new().ors(action);
+
+    if !group.is_empty() {
+      self.add_segment(format!("( {} )", group.build()));
+    }
+
+    self
+  }
+
   /// Start a LIMIT clause.
   ///
   /// # Example
@@ -738,9 +1051,17 @@ impl<'a> QueryBuilder<'a> {
 
         return self;
       }
+      // a connector right at the start of a group of conditions is dropped, as
+      // it is the case when the first condition was conditionally skipped:
+      (QueryBuilderInsertExceptions::ConditionStart, "AND" | "OR") => {
+        return self;
+      }
       (_, "AND" | "OR") => {
         self.insert_exceptions = QueryBuilderInsertExceptions::AndOr;
       }
+      (_, "WHERE" | "(") => {
+        self.insert_exceptions = QueryBuilderInsertExceptions::ConditionStart;
+      }
       _ => {
         self.insert_exceptions = QueryBuilderInsertExceptions::None;
       }
@@ -779,7 +1100,136 @@ impl<'a> QueryBuilder<'a> {
   /// assert_eq!("SELECT id FROM Account", query);
   /// ```
   pub fn param(mut self, key: &'a str, value: &'a str) -> Self {
-    self.parameters.insert(key, value);
+    self.parameters.insert(key, CowSegment::Borrowed(value));
+
+    self
+  }
+
+  /// Same as [QueryBuilder::param] but the builder takes ownership of the value,
+  /// so values computed at runtime can be interpolated without having to outlive
+  /// the builder.
+  ///
+  /// **IMPORTANT** Just like [QueryBuilder::param] or the [Sql](crate::types::Sql)
+  /// injecter, this is for trusted data only and not for user provided data,
+  /// the input is not sanitized.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let table = format!("archive_{}", 2023);
+  /// let query = QueryBuilder::new()
+  ///   .select("*")
+  ///   .from("{{table}}")
+  ///   .raw_param("{{table}}", table)
+  ///   .build();
+  ///
+  /// assert_eq!("SELECT * FROM archive_2023", query);
+  /// ```
+  pub fn raw_param(mut self, key: &'a str, value: String) -> Self {
+    self.parameters.insert(key, CowSegment::Owned(value));
+
+    self
+  }
+
+  /// Consumes the builder and returns its raw segments, used by the injecters
+  /// that need to rearrange what was pushed by other injecters.
+  #[cfg(feature = "queries")]
+  pub(crate) fn into_segments(self) -> Vec<CowSegment<'a>> {
+    self.segments
+  }
+
+  /// Returns `true` if one of the segments is exactly `segment`, used by the
+  /// injecters that need to know if a clause was already started.
+  #[cfg(feature = "queries")]
+  pub(crate) fn has_segment(&self, segment: &str) -> bool {
+    self.segments.iter().any(|s| s == segment)
+  }
+
+  /// Returns `true` if no segment was added to the builder.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// assert!(QueryBuilder::new().is_empty());
+  /// assert!(QueryBuilder::new().raw("").is_empty());
+  /// assert!(!QueryBuilder::new().raw("foo").is_empty());
+  /// ```
+  pub fn is_empty(&self) -> bool {
+    self.segments.is_empty()
+  }
+
+  /// Wraps the whole query in parentheses so it can be embedded as a value in a
+  /// larger statement, like a subquery in a condition or a function argument.
+  /// Nothing is added if the query is empty.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .select("*")
+  ///   .from("user")
+  ///   .wrap_parens()
+  ///   .build();
+  ///
+  /// assert_eq!(query, "( SELECT * FROM user )");
+  /// ```
+  pub fn wrap_parens(mut self) -> Self {
+    if self.is_empty() {
+      return self;
+    }
+
+    self.segments.insert(0, CowSegment::from("("));
+    self.segments.push(CowSegment::from(")"));
+
+    self
+  }
+
+  /// Turns the whole query into a `RETURN` statement of its parenthesized
+  /// output, see [QueryBuilder::wrap_parens].
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .select("count()")
+  ///   .from("user")
+  ///   .group_all()
+  ///   .as_return()
+  ///   .build();
+  ///
+  /// assert_eq!(query, "RETURN ( SELECT count() FROM user GROUP ALL )");
+  /// ```
+  pub fn as_return(self) -> Self {
+    let mut querybuilder = self.wrap_parens();
+    querybuilder.segments.insert(0, CowSegment::from("RETURN"));
+
+    querybuilder
+  }
+
+  /// Prepends a `/* text */` comment to the query, whatever was already added
+  /// to it. Useful to tag the queries so they can be traced back from the logs
+  /// of the database. Any `*/` in the text is escaped so it cannot close the
+  /// comment early.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .select("*")
+  ///   .from("user")
+  ///   .comment("trace:abc123")
+  ///   .build();
+  ///
+  /// assert_eq!(query, "/* trace:abc123 */ SELECT * FROM user");
+  /// ```
+  pub fn comment(mut self, text: &str) -> Self {
+    let comment = format!("/* {} */", text.replace("*/", "* /"));
+    self.segments.insert(0, CowSegment::from(comment));
 
     self
   }
@@ -788,16 +1238,82 @@ impl<'a> QueryBuilder<'a> {
     let mut output = self.segments.join(" ");
 
     for (key, value) in self.parameters {
-      let key_size = key.len();
+      if key.is_empty() {
+        continue;
+      }
 
-      while let Some(index) = output.find(key) {
-        output.replace_range(index..index + key_size, value);
+      // the search resumes after each replaced value, so a value that contains
+      // its own key is not substituted again.
+      let mut start = 0;
+      while let Some(offset) = output[start..].find(key) {
+        let index = start + offset;
+        output.replace_range(index..index + key.len(), &value);
+        start = index + value.len();
       }
     }
 
     output
   }
 
+  /// Same as [QueryBuilder::build] but runs a minimal structural check on the
+  /// segments first, so composition bugs like a dangling `SET` with no assignment
+  /// are caught before the query reaches the database.
+  ///
+  /// The check only ensures the parentheses are balanced and that no clause keyword
+  /// is immediately followed by another clause keyword, it is not a full SQL parser.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .update("user:john")
+  ///   .set("")
+  ///   .filter("age > 18")
+  ///   .build_validated();
+  ///
+  /// assert_eq!(query, Err(BuildError::EmptyClause("SET".to_owned())));
+  /// ```
+  pub fn build_validated(self) -> Result<String, BuildError> {
+    let mut depth: usize = 0;
+
+    for segment in &self.segments {
+      for c in segment.chars() {
+        match c {
+          '(' => depth += 1,
+          ')' => {
+            depth = depth
+              .checked_sub(1)
+              .ok_or(BuildError::UnbalancedParentheses)?
+          }
+          _ => {}
+        }
+      }
+    }
+
+    if depth != 0 {
+      return Err(BuildError::UnbalancedParentheses);
+    }
+
+    let is_clause = |segment: &CowSegment| CLAUSE_KEYWORDS.contains(&segment.as_ref());
+    for (index, segment) in self.segments.iter().enumerate() {
+      if !is_clause(segment) {
+        continue;
+      }
+
+      let is_empty = match self.segments.get(index + 1) {
+        Some(next) => is_clause(next) || next == "GROUP ALL",
+        None => true,
+      };
+
+      if is_empty {
+        return Err(BuildError::EmptyClause(segment.to_string()));
+      }
+    }
+
+    Ok(self.build())
+  }
+
   /// Start a SET statement with all the public fields in the supplied `T` using
   /// the [SqlFieldSerializer] and Serde to list all the serializable fields in order
   /// to get a statement like the following:
@@ -806,7 +1322,45 @@ impl<'a> QueryBuilder<'a> {
   /// ```
   ///
   /// The function is meant to be used with the models generated by the [model]
-  /// macro.
+  /// macro. Only the fields that are serialized get an assignment, so the fields
+  /// marked with `#[serde(skip_serializing)]` or skipped by a
+  /// `skip_serializing_if` are left out. Only the names of the fields are written
+  /// in the query, the values are expected to be bound to the parameters of the
+  /// same name, for example by binding the model itself.
+  ///
+  /// The error is the one returned by the `Serialize` implementation of `T`, if
+  /// any.
+  ///
+  /// # Example
+  /// ```
+  /// use serde::Serialize;
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// #[derive(Serialize)]
+  /// struct Account {
+  ///   handle: String,
+  ///   email: String,
+  ///
+  ///   #[serde(skip_serializing)]
+  ///   password: String,
+  /// }
+  ///
+  /// # fn main() -> Result<(), SqlSerializeError> {
+  /// let account = Account {
+  ///   handle: "john".to_owned(),
+  ///   email: "john@doe.com".to_owned(),
+  ///   password: "hunter2".to_owned(),
+  /// };
+  ///
+  /// let query = QueryBuilder::new()
+  ///   .create("Account")
+  ///   .set_model(&account)?
+  ///   .build();
+  ///
+  /// assert_eq!(query, "CREATE Account SET handle = $handle , email = $email");
+  /// # Ok(())
+  /// # }
+  /// ```
   #[cfg(feature = "model")]
   pub fn set_model<T: Serialize>(mut self, model: &T) -> SqlSerializeResult<Self> {
     let parameters = crate::model::to_parameters(model)?;
@@ -861,4 +1415,57 @@ impl<'a> QueryBuilder<'a> {
   pub fn injecter(self, injecter: &impl crate::queries::QueryBuilderInjecter<'a>) -> Self {
     injecter.inject(self)
   }
+
+  /// Build one statement per item using the injecters returned by the `statement`
+  /// function, then join the statements into a single query. The names of the
+  /// parameters of every statement are suffixed with the index of the item so the
+  /// rows do not overwrite each other's bindings. This is the usual way to write
+  /// many rows in a single query.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let ages = [("user:john", 20), ("user:jean", 30)];
+  /// let (query, params) =
+  ///   QueryBuilder::statements(ages, |(id, age)| (Update(id), Set(("age", age)))).unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "UPDATE user:john SET age = $age_0; UPDATE user:jean SET age = $age_1;"
+  /// );
+  /// assert_eq!(params.get("age_1"), Some(&serde_json::json!(30)));
+  /// ```
+  #[cfg(feature = "queries")]
+  pub fn statements<I, T>(
+    items: I, statement: impl Fn(I::Item) -> T,
+  ) -> crate::queries::QueryBuildResult<(String, crate::queries::BindingMap)>
+  where
+    I: IntoIterator,
+    T: crate::queries::QueryBuilderInjecter<'a> + 'a,
+  {
+    use crate::queries::rename_params;
+    use crate::queries::RESERVED_PARAMS;
+
+    let mut statements = Vec::new();
+    let mut bindings = crate::queries::BindingMap::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+      let injecter = statement(item);
+      let query = injecter.inject(QueryBuilder::new()).build();
+      statements.push(format!(
+        "{};",
+        rename_params(&query, |name| format!("{name}_{index}"))
+      ));
+
+      for (key, value) in crate::queries::bindings(injecter)? {
+        match RESERVED_PARAMS.contains(&key.as_str()) {
+          true => bindings.insert(key, value),
+          false => bindings.insert(format!("{key}_{index}"), value),
+        };
+      }
+    }
+
+    Ok((statements.join(" "), bindings))
+  }
 }
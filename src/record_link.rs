@@ -0,0 +1,48 @@
+use serde::ser::SerializeStruct;
+use serde::Serialize;
+
+/// The name of the struct the official SurrealDB client uses to recognize the
+/// record ids (`Thing`) during the serialization.
+const THING_TOKEN: &str = "$surrealdb::private::sql::Thing";
+
+/// A `table:id` record id that is serialized the same way the `Thing` type of
+/// the official client is.
+pub(crate) struct RecordLink<'a> {
+  pub table: &'a str,
+  pub id: &'a str,
+}
+
+impl<'a> Serialize for RecordLink<'a> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let mut record = serializer.serialize_struct(THING_TOKEN, 2)?;
+    record.serialize_field("tb", self.table)?;
+    record.serialize_field("id", &RecordId(self.id))?;
+    record.end()
+  }
+}
+
+/// The id part of a record link, numeric ids are kept as numbers like SurrealDB
+/// does when it parses `table:10`, and the `⟨⟩` around complex ids are removed.
+struct RecordId<'a>(&'a str);
+
+impl<'a> Serialize for RecordId<'a> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    if let Ok(number) = self.0.parse::<i64>() {
+      return serializer.serialize_newtype_variant("Id", 0, "Number", &number);
+    }
+
+    let id = self
+      .0
+      .strip_prefix('⟨')
+      .and_then(|id| id.strip_suffix('⟩'))
+      .unwrap_or(self.0);
+
+    serializer.serialize_newtype_variant("Id", 1, "String", id)
+  }
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+/// An aggregate function projection with an alias, meant to be used in the
+/// `what` part of SELECT statements:
+/// ```sql
+/// SELECT math::sum(amount) AS total FROM order GROUP BY customer
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// assert_eq!(Agg::sum("amount", "total").to_string(), "math::sum(amount) AS total");
+/// assert_eq!(Agg::count("id", "orders").to_string(), "count(id) AS orders");
+///
+/// let query = QueryBuilder::new()
+///   .select(Agg::mean("amount", "average").to_string())
+///   .from("order")
+///   .group_all()
+///   .build();
+///
+/// assert_eq!(query, "SELECT math::mean(amount) AS average FROM order GROUP ALL");
+/// ```
+pub struct Agg {
+  function: &'static str,
+  field: String,
+  alias: String,
+}
+
+impl Agg {
+  fn new(function: &'static str, field: impl Display, alias: impl Display) -> Self {
+    Self {
+      function,
+      field: field.to_string(),
+      alias: alias.to_string(),
+    }
+  }
+
+  /// `count(field) AS alias`
+  pub fn count(field: impl Display, alias: impl Display) -> Self {
+    Self::new("count", field, alias)
+  }
+
+  /// `math::sum(field) AS alias`
+  pub fn sum(field: impl Display, alias: impl Display) -> Self {
+    Self::new("math::sum", field, alias)
+  }
+
+  /// `math::mean(field) AS alias`
+  pub fn mean(field: impl Display, alias: impl Display) -> Self {
+    Self::new("math::mean", field, alias)
+  }
+
+  /// `math::min(field) AS alias`
+  pub fn min(field: impl Display, alias: impl Display) -> Self {
+    Self::new("math::min", field, alias)
+  }
+
+  /// `math::max(field) AS alias`
+  pub fn max(field: impl Display, alias: impl Display) -> Self {
+    Self::new("math::max", field, alias)
+  }
+}
+
+impl Display for Agg {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}({}) AS {}", self.function, self.field, self.alias)
+  }
+}
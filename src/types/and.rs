@@ -6,6 +6,11 @@ pub struct And<T>(pub T);
 
 impl<'a, T: QueryBuilderInjecter<'a>> QueryBuilderInjecter<'a> for And<T> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    // skip the AND keyword entirely if the inner injecter has nothing to add
+    if self.0.inject(QueryBuilder::new()).is_empty() {
+      return querybuilder;
+    }
+
     self.0.inject(querybuilder.and(""))
   }
 
@@ -26,3 +31,18 @@ impl<'a> QueryBuilderInjecter<'a> for And<&'a str> {
     Ok(())
   }
 }
+
+#[test]
+fn test_empty_and() {
+  use crate::prelude::*;
+
+  let filter = Where((("name", "John"), And(None::<(&str, i32)>)));
+  let (query, _) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user WHERE name = $name");
+
+  let filter = Where((("name", "John"), And(Or(()))));
+  let (query, _) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user WHERE name = $name");
+}
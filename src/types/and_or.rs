@@ -0,0 +1,92 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+
+/// Groups conditions in parentheses so the precedence of the query matches the
+/// structure of the injecters.
+///
+/// # Precedence
+/// The conditions of a tuple in a [Where](super::Where) are separated by `AND`s,
+/// and the [Or](super::Or) injecter separates its own conditions by `OR`s without
+/// any parentheses. Mixing both at the same level like `Where((a, Or((b, c))))`
+/// results in `a AND b OR c`, which leaves the precedence to the database.
+///
+/// `AndOr::Or` and `AndOr::And` join their conditions the same way but wrap them
+/// in parentheses, so the group is always evaluated as a whole. Empty groups add
+/// nothing to the query.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// // a AND (b OR c)
+/// let filter = Where((("a", 1), AndOr::Or((("b", 2), ("c", 3)))));
+/// let (query, _) = select("*", "t", filter).unwrap();
+/// assert_eq!(query, "SELECT * FROM t WHERE a = $a AND ( b = $b OR c = $c )");
+///
+/// // (a OR b) AND c
+/// let filter = Where((AndOr::Or((("a", 1), ("b", 2))), ("c", 3)));
+/// let (query, _) = select("*", "t", filter).unwrap();
+/// assert_eq!(query, "SELECT * FROM t WHERE ( a = $a OR b = $b ) AND c = $c");
+/// ```
+pub enum AndOr<T> {
+  /// `( a AND b )`
+  And(T),
+
+  /// `( a OR b )`
+  Or(T),
+}
+
+impl<'a, T> QueryBuilderInjecter<'a> for AndOr<T>
+where
+  T: QueryBuilderInjecter<'a>,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    match self {
+      AndOr::And(inner) => querybuilder.ands_group(|q| inner.inject(q)),
+      AndOr::Or(inner) => querybuilder.ors_group(|q| inner.inject(q)),
+    }
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    match self {
+      AndOr::And(inner) => inner.params(map),
+      AndOr::Or(inner) => inner.params(map),
+    }
+  }
+}
+
+#[test]
+fn test_and_or_precedence() {
+  use crate::prelude::*;
+
+  let filter = Where((("a", 1), AndOr::Or((("b", 2), ("c", 3)))));
+  let (query, params) = select("*", "t", filter).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM t WHERE a = $a AND ( b = $b OR c = $c )"
+  );
+  assert_eq!(params.len(), 3);
+
+  let filter = Where((AndOr::Or((("a", 1), ("b", 2))), ("c", 3)));
+  let (query, params) = select("*", "t", filter).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM t WHERE ( a = $a OR b = $b ) AND c = $c"
+  );
+  assert_eq!(params.len(), 3);
+
+  let filter = Where(AndOr::Or((
+    AndOr::And((("a", 1), ("b", 2))),
+    AndOr::And((("c", 3), ("d", 4))),
+  )));
+  let (query, _) = select("*", "t", filter).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM t WHERE ( ( a = $a AND b = $b ) OR ( c = $c AND d = $d ) )"
+  );
+
+  let filter = Where((("a", 1), AndOr::Or((None::<(&str, i32)>,))));
+  let (query, _) = select("*", "t", filter).unwrap();
+  assert_eq!(query, "SELECT * FROM t WHERE a = $a");
+}
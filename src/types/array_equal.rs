@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::Cmp;
+
+/// Checks that all the values of an array field are equal to the value, e.g
+/// `AllEqual(("tags", "rust"))` results in `tags *= $tags`.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, params) = select("*", "Book", Where(AllEqual(("tags", "rust")))).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM Book WHERE tags *= $tags");
+/// assert_eq!(params.get("tags"), Some(&serde_json::json!("rust")));
+/// ```
+pub struct AllEqual<T>(pub T);
+
+/// Checks that any of the values of an array field is equal to the value, e.g
+/// `AnyEqual(("tags", "rust"))` results in `tags ?= $tags`.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, params) = select("*", "Book", Where(AnyEqual(("tags", "rust")))).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM Book WHERE tags ?= $tags");
+/// assert_eq!(params.get("tags"), Some(&serde_json::json!("rust")));
+/// ```
+pub struct AnyEqual<T>(pub T);
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for AllEqual<(Key, Value)>
+where
+  Key: ToNodeBuilder + Display,
+  Value: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Cmp("*=", &self.0).inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    Cmp("*=", &self.0).params(map)
+  }
+}
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for AnyEqual<(Key, Value)>
+where
+  Key: ToNodeBuilder + Display,
+  Value: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Cmp("?=", &self.0).inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    Cmp("?=", &self.0).params(map)
+  }
+}
+
+#[test]
+fn test_array_equal() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let filter = Where((AllEqual(("tags", "rust")), AnyEqual(("scores", 10))));
+  let (query, params) = select("*", "Book", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE tags *= $tags AND scores ?= $scores"
+  );
+  assert_eq!(params.get("tags"), Some(&json!("rust")));
+  assert_eq!(params.get("scores"), Some(&json!(10)));
+}
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+
+type Operator = &'static str;
+
+/// A reference to the value a field had before the statement was executed, as
+/// exposed by SurrealDB through the `$before` variable.
+///
+/// # Example
+/// ```rs
+/// let set = Set(Before("version").plus(1));
+/// let (query, _params) = update("Document:a", set).unwrap();
+///
+/// assert_eq!("UPDATE Document:a SET version = $before.version + 1", query);
+/// ```
+pub struct Before<T>(pub T);
+
+/// A reference to the value a field has after the statement was executed, as
+/// exposed by SurrealDB through the `$after` variable.
+pub struct After<T>(pub T);
+
+/// An assignment of the form `field = $before.field <operator> <value>` that is
+/// obtained from the [Before] and [After] types.
+///
+/// Numeric values are written directly in the query as they cannot carry any
+/// injection, any other value is bound to a parameter named after the field.
+pub struct ReferenceExpression<Key, V> {
+  reference: &'static str,
+  key: Key,
+  operator: Operator,
+  value: V,
+}
+
+macro_rules! impl_reference {
+  ($name:ident, $reference:literal) => {
+    impl<Key: ToNodeBuilder> $name<Key> {
+      /// `field = $reference.field <operator> value`
+      pub fn op<V: Serialize>(self, operator: Operator, value: V) -> ReferenceExpression<Key, V> {
+        ReferenceExpression {
+          reference: $reference,
+          key: self.0,
+          operator,
+          value,
+        }
+      }
+
+      /// `field = $reference.field + value`
+      pub fn plus<V: Serialize>(self, value: V) -> ReferenceExpression<Key, V> {
+        self.op("+", value)
+      }
+
+      /// `field = $reference.field - value`
+      pub fn minus<V: Serialize>(self, value: V) -> ReferenceExpression<Key, V> {
+        self.op("-", value)
+      }
+    }
+
+    impl<Key: Display> Display for $name<Key> {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", $reference, self.0)
+      }
+    }
+  };
+}
+
+impl_reference!(Before, "$before");
+impl_reference!(After, "$after");
+
+impl<Key, V> ReferenceExpression<Key, V>
+where
+  Key: ToNodeBuilder,
+  V: Serialize,
+{
+  /// Returns the numeric value if the value is a number, as it can then be
+  /// written directly in the query.
+  fn inlined_value(&self) -> Option<serde_json::Number> {
+    match serde_json::to_value(&self.value) {
+      Ok(Value::Number(number)) => Some(number),
+      _ => None,
+    }
+  }
+}
+
+impl<'a, Key, V> QueryBuilderInjecter<'a> for ReferenceExpression<Key, V>
+where
+  Key: ToNodeBuilder,
+  V: Serialize,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let value = match self.inlined_value() {
+      Some(number) => number.to_string(),
+      None => format!("${}", self.key.as_param()),
+    };
+
+    querybuilder.add_segment(format!(
+      "{key} = {reference}.{key} {operator} {value}",
+      key = self.key,
+      reference = self.reference,
+      operator = self.operator,
+    ));
+
+    querybuilder
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    if self.inlined_value().is_none() {
+      map.insert(self.key.as_param(), ser_to_param_value(self.value)?);
+    }
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_before_after() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let set = Set(Before("version").plus(1));
+  let (query, params) = update("Document:a", set).unwrap();
+
+  assert_eq!("UPDATE Document:a SET version = $before.version + 1", query);
+  assert!(params.is_empty());
+
+  let set = Set(After("title").op("+", "(edited)"));
+  let (query, params) = update("Document:a", set).unwrap();
+
+  assert_eq!("UPDATE Document:a SET title = $after.title + $title", query);
+  assert_eq!(params.get("title"), Some(&Value::from("(edited)")));
+
+  assert_eq!("$before.version", Before("version").to_string());
+}
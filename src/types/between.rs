@@ -0,0 +1,185 @@
+use std::fmt::Display;
+use std::ops::Range;
+use std::ops::RangeInclusive;
+
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+use super::Equal;
+
+/// Wraps a range so it can be used as the value of a key/value pair, the pair
+/// then results in a two-sided comparison rather than in an equality. The upper
+/// bound is included only for the inclusive ranges:
+/// ```sql
+/// -- ("age", Between(18..65))
+/// age >= $age_min AND age < $age_max
+///
+/// -- ("age", Between(18..=65))
+/// age >= $age_min AND age <= $age_max
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, params) = select("*", "user", Where(("age", Between(18..65)))).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM user WHERE age >= $age_min AND age < $age_max");
+/// assert_eq!(params.get("age_min"), Some(&serde_json::json!(18)));
+/// assert_eq!(params.get("age_max"), Some(&serde_json::json!(65)));
+/// ```
+pub struct Between<R>(pub R);
+
+impl Between<()> {
+  /// `key >= $key_min AND key < $key_max`, or `<=` for the inclusive ranges.
+  /// Both comparisons are in the same segment so they stay together even if the
+  /// pair is in a group of `OR` conditions.
+  fn between_inject<'a>(
+    mut querybuilder: QueryBuilder<'a>, key: &impl ToNodeBuilder, inclusive: bool,
+  ) -> QueryBuilder<'a> {
+    let param = key.as_param();
+    let end_operator = match inclusive {
+      true => "<=",
+      false => "<",
+    };
+
+    querybuilder.add_segment(format!(
+      "{key} >= ${param}_min AND {key} {end_operator} ${param}_max"
+    ));
+
+    querybuilder
+  }
+
+  fn between_params(
+    map: &mut BindingMap, key: &impl ToNodeBuilder, start: impl Serialize, end: impl Serialize,
+  ) -> serde_json::Result<()> {
+    map.insert(
+      format!("{}_min", key.as_param()),
+      ser_to_param_value(start)?,
+    );
+    map.insert(format!("{}_max", key.as_param()), ser_to_param_value(end)?);
+
+    Ok(())
+  }
+}
+
+impl<'a, V> QueryBuilderInjecter<'a> for (&str, Between<Range<V>>)
+where
+  V: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Between::between_inject(querybuilder, &self.0, false)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let range = self.1 .0;
+
+    Between::between_params(map, &self.0, range.start, range.end)
+  }
+}
+
+impl<'a, V> QueryBuilderInjecter<'a> for (&str, Between<RangeInclusive<V>>)
+where
+  V: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Between::between_inject(querybuilder, &self.0, true)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let (start, end) = self.1 .0.into_inner();
+
+    Between::between_params(map, &self.0, start, end)
+  }
+}
+
+#[cfg(feature = "model")]
+use crate::prelude::SchemaField;
+
+#[cfg(feature = "model")]
+impl<'a, V, const N: usize> QueryBuilderInjecter<'a> for (SchemaField<N>, Between<Range<V>>)
+where
+  V: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Between::between_inject(querybuilder, &self.0, false)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let range = self.1 .0;
+
+    Between::between_params(map, &self.0, range.start, range.end)
+  }
+}
+
+#[cfg(feature = "model")]
+impl<'a, V, const N: usize> QueryBuilderInjecter<'a>
+  for (SchemaField<N>, Between<RangeInclusive<V>>)
+where
+  V: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Between::between_inject(querybuilder, &self.0, true)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let (start, end) = self.1 .0.into_inner();
+
+    Between::between_params(map, &self.0, start, end)
+  }
+}
+
+impl<'a, Key, V> QueryBuilderInjecter<'a> for Equal<(Key, Between<Range<V>>)>
+where
+  Key: ToNodeBuilder + Display,
+  V: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Between::between_inject(querybuilder, &self.0 .0, false)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let (key, Between(range)) = self.0;
+
+    Between::between_params(map, &key, range.start, range.end)
+  }
+}
+
+impl<'a, Key, V> QueryBuilderInjecter<'a> for Equal<(Key, Between<RangeInclusive<V>>)>
+where
+  Key: ToNodeBuilder + Display,
+  V: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Between::between_inject(querybuilder, &self.0 .0, true)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let (key, Between(range)) = self.0;
+    let (start, end) = range.into_inner();
+
+    Between::between_params(map, &key, start, end)
+  }
+}
+
+#[test]
+fn test_between() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let filter = Where((("name", "John"), ("age", Between(18..=65))));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE name = $name AND age >= $age_min AND age <= $age_max"
+  );
+  assert_eq!(params.get("age_min"), Some(&json!(18)));
+  assert_eq!(params.get("age_max"), Some(&json!(65)));
+  assert_eq!(params.get("age"), None);
+}
@@ -9,6 +9,12 @@ use crate::prelude::QueryBuilderInjecter;
 ///   "key": "value",
 ///   "foo": 10
 /// }));
+/// Bind([("a", 1), ("b", 2)]);
+/// ```
+///
+/// Values of different types can be bound at once using a tuple of binds:
+/// ```rs
+/// (Bind(("name", "John")), Bind(("age", 10)));
 /// ```
 pub struct Bind<T>(pub T);
 
@@ -33,3 +39,76 @@ impl<'a> QueryBuilderInjecter<'a> for Bind<serde_json::Value> {
     self.0.params(map)
   }
 }
+
+impl<'a, Key, V, const N: usize> QueryBuilderInjecter<'a> for Bind<[(Key, V); N]>
+where
+  Key: crate::node_builder::ToNodeBuilder,
+  V: Serialize,
+{
+  fn params(self, map: &mut crate::queries::BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    for (key, value) in self.0 {
+      super::Equal::equal_params(map, &key, value)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<'a, Key, V> QueryBuilderInjecter<'a> for Bind<&[(Key, V)]>
+where
+  Key: crate::node_builder::ToNodeBuilder,
+  V: Serialize,
+{
+  fn params(self, map: &mut crate::queries::BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    for (key, value) in self.0 {
+      super::Equal::equal_params(map, key, value)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<'a, Key, V> QueryBuilderInjecter<'a> for Bind<Vec<(Key, V)>>
+where
+  Key: crate::node_builder::ToNodeBuilder,
+  V: Serialize,
+{
+  fn params(self, map: &mut crate::queries::BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    Bind(self.0.as_slice()).params(map)
+  }
+}
+
+#[test]
+fn test_bind_many() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let (query, params) = select(
+    "*",
+    "User",
+    (
+      Sql("WHERE age > $min AND age < $max AND name != $name"),
+      Bind([("min", 10), ("max", 20)]),
+      Bind(vec![("name", "John")]),
+    ),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM User WHERE age > $min AND age < $max AND name != $name"
+  );
+  assert_eq!(params.len(), 3);
+  assert_eq!(params.get("min"), Some(&Value::from(10)));
+  assert_eq!(params.get("max"), Some(&Value::from(20)));
+  assert_eq!(params.get("name"), Some(&Value::from("John")));
+}
@@ -1,21 +1,73 @@
+use serde::Serialize;
+
 use crate::prelude::QueryBuilder;
 use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+
+use super::flatten_serialize;
+
+/// Turns any serializable value into an injecter, the value is serialized then
+/// flattened using [flatten_serialize] and every field results in a
+/// `field = $field` pair, exactly like passing a `serde_json::Value` does. Nested
+/// fields are flattened using dots, so they result in `author.name = $author_name`.
+///
+/// It allows you to pass your own structs as filters without going through the
+/// `json!` macro.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct BookFilter {
+///   title: &'static str,
+///   read: bool,
+/// }
+///
+/// let filter = Where(Build(BookFilter { title: "Lorem Ipsum", read: true }));
+/// let (query, params) = select("*", "Book", filter).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM Book WHERE title = $title AND read = $read");
+/// assert_eq!(params.get("read"), Some(&serde_json::Value::Bool(true)));
+/// ```
+pub struct Build<T>(pub T);
+
+impl<'a, T: Serialize> QueryBuilderInjecter<'a> for Build<T> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    // a value that fails to serialize injects nothing, the error is returned by
+    // the `params` function instead.
+    match flatten_serialize(&self.0) {
+      Ok(value) => value.inject(querybuilder),
+      Err(_) => querybuilder,
+    }
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    flatten_serialize(self.0)
+      .map_err(<serde_json::Error as serde::ser::Error>::custom)?
+      .params(map)
+  }
+}
 
 /// An unintuitive way to access the underlying querybuilder that is passed to
 /// the querybuilder injecters. It is considered unintuitive because if you want
 /// an access to the raw querybuilder while  in injecters then you may as well just
 /// use the querybuilder without the injecters, but there are special cases where
-/// you may want to use injecters everywhere (which is valid) and this `Build`
+/// you may want to use injecters everywhere (which is valid) and this `BuildFn`
 /// type allows you to play around the current limitations of the injecters without
 /// completely giving up on them.
 ///
 /// If the function that mutates the builder uses variable then you should also
 /// use the [`Bind`] injecter to inject them.
-pub struct Build<T>(pub T)
+///
+/// This type used to be named `Build`, the name now belongs to the injecter for
+/// the serializable values so the closures must be wrapped in a `BuildFn`.
+pub struct BuildFn<T>(pub T)
 where
   T: Fn(QueryBuilder) -> QueryBuilder;
 
-impl<'a, T> QueryBuilderInjecter<'a> for Build<T>
+impl<'a, T> QueryBuilderInjecter<'a> for BuildFn<T>
 where
   T: Fn(QueryBuilder) -> QueryBuilder,
 {
@@ -23,3 +75,33 @@ where
     self.0(querybuilder)
   }
 }
+
+#[test]
+fn test_build() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  #[derive(Serialize)]
+  struct Author {
+    name: &'static str,
+  }
+
+  #[derive(Serialize)]
+  struct BookFilter {
+    title: &'static str,
+    author: Author,
+  }
+
+  let filter = Where(Build(BookFilter {
+    title: "Lorem Ipsum",
+    author: Author { name: "John" },
+  }));
+  let (query, params) = select("*", "Book", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE title = $title AND author.name = $author_name"
+  );
+  assert_eq!(params.get("title"), Some(&Value::from("Lorem Ipsum")));
+  assert_eq!(params.get("author_name"), Some(&Value::from("John")));
+}
@@ -9,9 +9,16 @@ use crate::prelude::ToNodeBuilder;
 use crate::queries::BindingMap;
 
 use super::ser_to_param_value;
+use super::Sql;
 
 type Operator = &'static str;
 
+/// Compares a key to a value using the supplied operator, e.g `Cmp(">=", ("age", 18))`
+/// results in `age >= $age`.
+///
+/// The name of the parameter is obtained the same way as for [Equal](super::Equal),
+/// so the fields of the models are supported as well, including the nested ones:
+/// `Cmp(">", (book.author().age, 18))` results in `author.age > $author_age`.
 pub struct Cmp<T>(pub Operator, pub T);
 
 /// Base functions for all implementations of the `QueryBuilderInjecter` trait
@@ -67,6 +74,29 @@ where
   }
 }
 
+/// The raw SQL is written as is on the right hand side and nothing is bound,
+/// e.g `Cmp(">=", ("created", Sql("time::now()")))` results in `created >= time::now()`.
+///
+/// Anything that implements `Display` can be wrapped, so the fields of the models
+/// can be compared with each other: `Cmp("<", (post.created_at, Sql(post.updated_at)))`
+/// results in `created_at < updated_at`. The same goes for [Equal](super::Equal),
+/// [Greater](super::Greater) and [Lower](super::Lower).
+impl<'a, Key, Rhs> QueryBuilderInjecter<'a> for Cmp<(Key, Sql<Rhs>)>
+where
+  Key: ToNodeBuilder + Display,
+  Rhs: Display,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.1 .0.compares(self.0, &self.1 .1 .0.to_string()));
+
+    querybuilder
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
+  }
+}
+
 impl<'a> QueryBuilderInjecter<'a> for Cmp<Value> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
     if let Some(map) = self.1.as_object() {
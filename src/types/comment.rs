@@ -0,0 +1,44 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+/// Prepends a comment to the query, wherever the injecter is placed. It has no
+/// bindings and any `*/` in the text is escaped:
+/// ```sql
+/// /* trace:abc123 */ SELECT * FROM user
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, _params) = select("*", "user", (Where(("handle", "john")), Comment("trace:abc123"))).unwrap();
+///
+/// assert_eq!(query, "/* trace:abc123 */ SELECT * FROM user WHERE handle = $handle");
+/// ```
+pub struct Comment<'b>(pub &'b str);
+
+impl<'a, 'b> QueryBuilderInjecter<'a> for Comment<'b> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.comment(self.0)
+  }
+}
+
+#[test]
+fn test_comment() {
+  use crate::prelude::*;
+
+  let filter = (Comment("trace:abc123"), Where(("handle", "john")));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "/* trace:abc123 */ SELECT * FROM user WHERE handle = $handle"
+  );
+  assert_eq!(params.len(), 1);
+
+  let query = QueryBuilder::new()
+    .select("*")
+    .injecter(&Comment("*/ DELETE user; /*"))
+    .build();
+  assert_eq!(query, "/* * / DELETE user; /* */ SELECT *");
+}
@@ -0,0 +1,88 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+use crate::queries::DynInjecter;
+
+/// A list of conditions of different types that is built at runtime, when
+/// placed in a [Where](super::Where) the conditions are joined with `AND`:
+/// ```sql
+/// SELECT * FROM User WHERE name = $name AND age > $age
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let min_age = Some(18);
+/// let mut conditions = Conditions::new();
+/// conditions.push(("name", "John"));
+///
+/// if let Some(age) = min_age {
+///   conditions.push(Greater(("age", age)));
+/// }
+///
+/// let (query, _) = select("*", "User", Where(conditions)).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM User WHERE name = $name AND age > $age");
+/// ```
+#[derive(Default)]
+pub struct Conditions<'a>(pub Vec<Box<dyn DynInjecter<'a> + 'a>>);
+
+impl<'a> Conditions<'a> {
+  pub fn new() -> Self {
+    Self(Vec::new())
+  }
+
+  pub fn push(&mut self, condition: impl QueryBuilderInjecter<'a> + 'a) {
+    self.0.push(Box::new(condition));
+  }
+
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for Conditions<'a> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    self.0.inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    self.0.params(map)
+  }
+}
+
+#[test]
+fn test_conditions() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let mut conditions = Conditions::new();
+
+  for (field, value) in [("name", json!("John")), ("age", json!(18))] {
+    match value.is_number() {
+      true => conditions.push(Greater((field, value))),
+      false => conditions.push((field, value)),
+    }
+  }
+
+  conditions.push(Lower(("score", 100)));
+
+  let (query, params) = select("*", "User", Where(conditions)).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM User WHERE name = $name AND age > $age AND score < $score"
+  );
+  assert_eq!(params.len(), 3);
+  assert_eq!(params.get("name"), Some(&json!("John")));
+
+  let (query, params) = select("*", "User", Where(Conditions::new())).unwrap();
+
+  assert_eq!(query, "SELECT * FROM User");
+  assert!(params.is_empty());
+}
@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+
+/// Writes a `CONTENT $content` clause and binds the whole serialized value to the
+/// `content` parameter.
+///
+/// Unlike the [Set](super::Set) injecter, nested objects are sent as is instead
+/// of being flattened into `author.name = $author_name` assignments, so it is
+/// the preferred option when the value holds nested objects or arrays of
+/// objects. Note that `CONTENT` replaces the whole record while `SET` only
+/// updates the assigned fields.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let content = Content(json!({ "name": "John", "address": { "city": "Paris" } }));
+/// let (query, params) = create("User", content).unwrap();
+///
+/// assert_eq!(query, "CREATE User CONTENT $content");
+/// assert_eq!(params.get("content"), Some(&json!({ "name": "John", "address": { "city": "Paris" } })));
+/// ```
+pub struct Content<T>(pub T);
+
+impl<'a, T: Serialize> QueryBuilderInjecter<'a> for Content<T> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.content("$content")
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    map.insert("content".to_owned(), ser_to_param_value(self.0)?);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+use super::OrderDirection;
+
+/// Keyset pagination, the scalable alternative to the [Pagination](super::Pagination)
+/// injecter for large tables. Rather than skipping the first rows with a
+/// `START AT` clause the rows are filtered using the last value that was seen,
+/// which is bound to the `$cursor` parameter:
+/// ```sql
+/// SELECT * FROM user WHERE created > $cursor ORDER BY created ASC LIMIT 20
+/// ```
+///
+/// The condition is not written when there is no last value, as it is the case
+/// for the first page. If the query already has a WHERE clause then the condition
+/// is appended to it, in which case the cursor must come after the [Where](super::Where)
+/// injecter.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let cursor = Cursor::after("created", Some("2023-01-01"), 20);
+/// let (query, params) = select("*", "user", (Where(("active", true)), cursor)).unwrap();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT * FROM user WHERE active = $active AND created > $cursor ORDER BY created ASC LIMIT 20"
+/// );
+/// assert_eq!(params.get("cursor"), Some(&serde_json::json!("2023-01-01")));
+/// ```
+pub struct Cursor<K, V> {
+  field: K,
+  last: Option<V>,
+  direction: OrderDirection,
+  limit: u64,
+}
+
+impl<K, V> Cursor<K, V> {
+  /// The `limit` rows that come after the `last` value in ascending order,
+  /// `field > $cursor ORDER BY field ASC`
+  pub fn after(field: K, last: Option<V>, limit: u64) -> Self {
+    Self {
+      field,
+      last,
+      direction: OrderDirection::Asc,
+      limit,
+    }
+  }
+
+  /// The `limit` rows that come before the `last` value in descending order,
+  /// `field < $cursor ORDER BY field DESC`
+  pub fn before(field: K, last: Option<V>, limit: u64) -> Self {
+    Self {
+      field,
+      last,
+      direction: OrderDirection::Desc,
+      limit,
+    }
+  }
+}
+
+impl<'a, K, V> QueryBuilderInjecter<'a> for Cursor<K, V>
+where
+  K: Display,
+  V: Serialize,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let field = self.field.to_string();
+
+    if self.last.is_some() {
+      let operator = match self.direction {
+        OrderDirection::Asc => ">",
+        OrderDirection::Desc => "<",
+      };
+      let condition = format!("{field} {operator} $cursor");
+
+      querybuilder = match querybuilder.has_segment("WHERE") {
+        true => querybuilder.and(condition),
+        false => querybuilder.filter(condition),
+      };
+    }
+
+    let querybuilder = match self.direction {
+      OrderDirection::Asc => querybuilder.order_by_asc(field),
+      OrderDirection::Desc => querybuilder.order_by_desc(field),
+    };
+
+    querybuilder.limit(self.limit.to_string())
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    if let Some(last) = self.last {
+      map.insert("cursor".to_owned(), ser_to_param_value(last)?);
+    }
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_cursor() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let (query, params) = select("*", "user", Cursor::after("created", Some(10), 20)).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE created > $cursor ORDER BY created ASC LIMIT 20"
+  );
+  assert_eq!(params.get("cursor"), Some(&json!(10)));
+
+  let (query, params) = select("*", "user", Cursor::before("created", Some(10), 20)).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE created < $cursor ORDER BY created DESC LIMIT 20"
+  );
+  assert_eq!(params.get("cursor"), Some(&json!(10)));
+
+  let first_page = Cursor::after("created", None::<i32>, 20);
+  let (query, params) = select("*", "user", (Where(("active", true)), first_page)).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE active = $active ORDER BY created ASC LIMIT 20"
+  );
+  assert!(!params.contains_key("cursor"));
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+/// Starts a SELECT statement on the distinct values of the given field. SurrealDB
+/// has no `DISTINCT` keyword and expresses it with the `array::distinct` function
+/// instead, so the emitted form is the following:
+/// ```sql
+/// SELECT array::distinct(tags) FROM post
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let query = query(&(Distinct("tags"), From("post"))).unwrap();
+///
+/// assert_eq!(query, "SELECT array::distinct(tags) FROM post");
+/// ```
+pub struct Distinct<T>(pub T);
+
+impl<'a, T: Display> QueryBuilderInjecter<'a> for Distinct<T> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.select(format!("array::distinct({})", self.0))
+  }
+}
@@ -8,8 +8,10 @@ use crate::prelude::QueryBuilderInjecter;
 use crate::prelude::ToNodeBuilder;
 use crate::queries::BindingMap;
 
+use super::optional::is_none;
 use super::ser_to_param_value;
 use super::to_param_value;
+use super::Sql;
 
 pub struct Equal<T>(pub T);
 
@@ -30,6 +32,27 @@ impl Equal<()> {
 
     Ok(())
   }
+
+  /// Same as [Equal::equal_inject] but nothing is injected if the value is a
+  /// `None`, so optional filters drop the whole key/value pair.
+  pub(crate) fn optional_equal_inject<'a>(
+    querybuilder: QueryBuilder<'a>, key: &impl ToNodeBuilder, value: &impl Serialize,
+  ) -> QueryBuilder<'a> {
+    match is_none(value) {
+      true => querybuilder,
+      false => Equal::equal_inject(querybuilder, key),
+    }
+  }
+
+  /// Same as [Equal::equal_params] but nothing is bound if the value is a `None`
+  pub(crate) fn optional_equal_params(
+    map: &mut BindingMap, key: &impl ToNodeBuilder, value: impl Serialize,
+  ) -> serde_json::Result<()> {
+    match is_none(&value) {
+      true => Ok(()),
+      false => Equal::equal_params(map, key, value),
+    }
+  }
 }
 
 impl<'a, Value> QueryBuilderInjecter<'a> for &(&str, Value)
@@ -37,14 +60,14 @@ where
   Value: Serialize,
 {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    Equal::equal_inject(querybuilder, &self.0)
+    Equal::optional_equal_inject(querybuilder, &self.0, &self.1)
   }
 
   fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
   where
     Self: Sized,
   {
-    Equal::equal_params(map, &self.0, &self.1)
+    Equal::optional_equal_params(map, &self.0, &self.1)
   }
 }
 
@@ -53,14 +76,27 @@ where
   Value: Serialize,
 {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    Equal::equal_inject(querybuilder, &self.0)
+    Equal::optional_equal_inject(querybuilder, &self.0, &self.1)
   }
 
   fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
   where
     Self: Sized,
   {
-    Equal::equal_params(map, &self.0, &self.1)
+    Equal::optional_equal_params(map, &self.0, &self.1)
+  }
+}
+
+/// Writes the raw SQL as is on the right hand side, so it can be used for
+/// assignments that are expressions rather than values:
+/// `Set(("updated_at", Sql("time::now()")))` results in `SET updated_at = time::now()`
+impl<'a> QueryBuilderInjecter<'a> for (&str, Sql<&str>) {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Equal((self.0, Sql(self.1 .0))).inject(querybuilder)
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
   }
 }
 
@@ -73,14 +109,45 @@ where
   Value: Serialize,
 {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    Equal::equal_inject(querybuilder, &self.0)
+    Equal::optional_equal_inject(querybuilder, &self.0, &self.1)
   }
 
   fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
   where
     Self: Sized,
   {
-    Equal::equal_params(map, &self.0, &self.1)
+    Equal::optional_equal_params(map, &self.0, &self.1)
+  }
+}
+
+#[cfg(feature = "model")]
+use crate::prelude::DynamicField;
+
+#[cfg(feature = "model")]
+impl<'a, Value> QueryBuilderInjecter<'a> for (DynamicField, Value)
+where
+  Value: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Equal::optional_equal_inject(querybuilder, &self.0, &self.1)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    Equal::optional_equal_params(map, &self.0, &self.1)
+  }
+}
+
+#[cfg(feature = "model")]
+impl<'a, const N: usize> QueryBuilderInjecter<'a> for (SchemaField<N>, Sql<&str>) {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Equal((self.0, Sql(self.1 .0))).inject(querybuilder)
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
   }
 }
 
@@ -139,14 +206,14 @@ where
   Value: Serialize,
 {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    Equal::equal_inject(querybuilder, &self.0 .0)
+    Equal::optional_equal_inject(querybuilder, &self.0 .0, &self.0 .1)
   }
 
   fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
   where
     Self: Sized,
   {
-    Equal::equal_params(map, &self.0 .0, &self.0 .1)
+    Equal::optional_equal_params(map, &self.0 .0, &self.0 .1)
   }
 }
 
@@ -167,6 +234,24 @@ where
   }
 }
 
+/// The raw SQL is written as is on the right hand side and nothing is bound,
+/// e.g `Equal(("updated", Sql("created")))` results in `updated = created`.
+impl<'a, Key, Rhs> QueryBuilderInjecter<'a> for Equal<(Key, Sql<Rhs>)>
+where
+  Key: ToNodeBuilder + Display,
+  Rhs: Display,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.0 .0.equals(&self.0 .1 .0.to_string()));
+
+    querybuilder
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
+  }
+}
+
 impl<'a> QueryBuilderInjecter<'a> for Equal<Value> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
     self.0.inject(querybuilder)
@@ -192,3 +277,40 @@ where
     self.0.params(map)
   }
 }
+
+#[test]
+fn test_optional_value() {
+  use crate::prelude::*;
+
+  let filter = Where((("name", None::<&str>), ("age", Some(10))));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user WHERE age = $age");
+  assert_eq!(params.get("name"), None);
+  assert_eq!(params.get("age"), Some(&serde_json::Value::from(10)));
+
+  let filter = Where(("name", None::<&str>));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user");
+  assert!(params.is_empty());
+
+  // only the `None` values are dropped, the explicit nulls are still bound
+  #[derive(Serialize)]
+  struct Unit;
+
+  let filter = Where((
+    ("deleted", Value::Null),
+    ("archived", Some(None::<bool>)),
+    ("flag", Unit),
+  ));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE deleted = $deleted AND archived = $archived AND flag = $flag"
+  );
+  assert_eq!(params.get("deleted"), Some(&Value::Null));
+  assert_eq!(params.get("archived"), Some(&Value::Null));
+  assert_eq!(params.get("flag"), Some(&Value::Null));
+}
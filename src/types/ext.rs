@@ -83,5 +83,46 @@ pub fn flatten_serialize(
     .set_key_separator(".")
     .flatten(&value)?;
 
-  Ok(flattened)
+  Ok(restore_tagged_values(flattened))
 }
+
+/// The tagged objects the [SurrealDuration](super::SurrealDuration) and
+/// [SurrealDatetime](super::SurrealDatetime) wrappers serialize into are leaf
+/// values, so the `field.$surreal_duration` keys the flattening produced are
+/// turned back into a `field` key holding the tagged object.
+fn restore_tagged_values(value: serde_json::Value) -> serde_json::Value {
+  let serde_json::Value::Object(map) = value else {
+    return value;
+  };
+
+  let restored = map
+    .into_iter()
+    .map(|(key, value)| {
+      let tag = TAGS
+        .iter()
+        .find(|tag| key.ends_with(**tag) && key[..key.len() - tag.len()].ends_with('.'));
+
+      match tag {
+        Some(tag) => {
+          let field = key[..key.len() - tag.len() - 1].to_owned();
+          let tagged =
+            serde_json::Value::Object(serde_json::Map::from_iter([(tag.to_string(), value)]));
+
+          (field, tagged)
+        }
+        None => (key, value),
+      }
+    })
+    .collect();
+
+  serde_json::Value::Object(restored)
+}
+
+#[cfg(not(feature = "chrono"))]
+const TAGS: [&str; 1] = [super::surreal_value::DURATION_TAG];
+
+#[cfg(feature = "chrono")]
+const TAGS: [&str; 2] = [
+  super::surreal_value::DURATION_TAG,
+  super::surreal_value::DATETIME_TAG,
+];
@@ -14,3 +14,12 @@ impl<'a> QueryBuilderInjecter<'a> for Fetch<&[&'a str]> {
     querybuilder.fetch_many(&self.0)
   }
 }
+
+#[test]
+fn test_fetch_duplicates() {
+  use crate::prelude::*;
+
+  let (query, _) = select("*", "Book", Fetch(["author", "readers", "author"])).unwrap();
+
+  assert_eq!(query, "SELECT * FROM Book FETCH author , readers");
+}
@@ -2,6 +2,8 @@ use crate::prelude::QueryBuilder;
 use crate::prelude::QueryBuilderInjecter;
 use crate::queries::BindingMap;
 
+use super::Prefixed;
+
 /// Add a WHERE clause to the query, the `Where` type is made to accept anything
 /// that implements the [QueryBuilderInjecter] trait, meaning any of the injecter
 /// types that come with the crate + your own.
@@ -29,6 +31,17 @@ use crate::queries::BindingMap;
 /// as the [Equal] injecter. In the same style, passing an `Option<T>` as the value
 /// can be used to pass an optional filter, where the whole key/value pair will
 /// be ignored on a `None`
+///
+/// A range wrapped in a [Between](super::Between) as the value results in a
+/// two-sided comparison, with the upper bound included only for the inclusive
+/// ranges:
+/// ```rs
+/// // age >= $age_min AND age < $age_max
+/// let filter = Where((schema.age, Between(18..65)));
+///
+/// // age >= $age_min AND age <= $age_max
+/// let filter = Where((schema.age, Between(18..=65)));
+/// ```
 pub struct Where<T>(pub T);
 
 /// An alias macro for
@@ -49,6 +62,12 @@ macro_rules! wjson {
 
 impl<'a, T: QueryBuilderInjecter<'a>> QueryBuilderInjecter<'a> for Where<T> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    // skip the WHERE keyword entirely if the inner injecter has nothing to add,
+    // as it is the case when all of the optional filters are `None`
+    if self.0.inject(QueryBuilder::new()).is_empty() {
+      return querybuilder;
+    }
+
     querybuilder.filter("").ands(|q| self.0.inject(q))
   }
 
@@ -68,12 +87,34 @@ where
     Where((self.0, other))
   }
 
-  pub fn extend_on<Other, Output>(
-    self, condition: bool, other: Other,
-  ) -> Where<(Own, Option<Other>)>
+  /// Same as [Where::extend] but the names of the parameters of the `other`
+  /// injecter are prefixed using the [Prefixed] injecter, so both injecters can
+  /// filter the same fields without their bindings colliding.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let filter = Where(("age", 18)).extend_named("max", Cmp("<", ("age", 30)));
+  /// let (query, params) = select("*", "user", filter).unwrap();
+  ///
+  /// assert_eq!(query, "SELECT * FROM user WHERE age = $age AND age < $max_age");
+  /// assert_eq!(params.len(), 2);
+  /// ```
+  pub fn extend_named<Other>(
+    self, prefix: &'static str, other: Other,
+  ) -> Where<(Own, Prefixed<Other>)>
+  where
+    Other: QueryBuilderInjecter<'a>,
+  {
+    Where((self.0, Prefixed(prefix, other)))
+  }
+
+  /// Same as [Where::extend] but the `other` injecter is only used if the
+  /// `condition` is `true`.
+  pub fn extend_on<Other>(self, condition: bool, other: Other) -> Where<(Own, Option<Other>)>
   where
     Other: QueryBuilderInjecter<'a>,
-    Output: QueryBuilderInjecter<'a>,
   {
     match condition {
       true => Where((self.0, Some(other))),
@@ -81,3 +122,56 @@ where
     }
   }
 }
+
+#[test]
+fn test_empty_where() {
+  use crate::prelude::*;
+
+  let filter = Where((None::<(&str, i32)>,));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user");
+  assert!(params.is_empty());
+
+  let filter = Where((None::<(&str, i32)>, Some(("age", 10))));
+  let (query, _) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user WHERE age = $age");
+}
+
+#[test]
+fn test_extend_on() {
+  use crate::prelude::*;
+
+  let filter = Where(("name", "John")).extend_on(true, ("role", "admin"));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE name = $name AND role = $role"
+  );
+  assert!(params.contains_key("role"));
+
+  let filter = Where(("name", "John")).extend_on(false, ("role", "admin"));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM user WHERE name = $name");
+  assert!(!params.contains_key("role"));
+}
+
+#[test]
+fn test_extend_named() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let filter = Where(("id", "user:john")).extend_named("override", ("id", "user:jean"));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE id = $id AND id = $override_id"
+  );
+  assert_eq!(params.len(), 2);
+  assert_eq!(params.get("id"), Some(&json!("user:john")));
+  assert_eq!(params.get("override_id"), Some(&json!("user:jean")));
+}
@@ -1,10 +1,16 @@
 use crate::prelude::QueryBuilder;
 use crate::prelude::QueryBuilderInjecter;
+use crate::querybuilder::CowSegment;
 
-pub struct From(pub &'static str);
+/// Starts a FROM clause with the given table, which can either be a
+/// `&'static str` or an owned string built at runtime.
+pub struct From<T = &'static str>(pub T);
 
-impl<'a> QueryBuilderInjecter<'a> for From {
+impl<'a, T> QueryBuilderInjecter<'a> for From<T>
+where
+  T: Into<CowSegment<'a>> + Clone,
+{
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    querybuilder.from(self.0)
+    querybuilder.from(self.0.clone())
   }
 }
@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::Cmp;
+
+/// The geospatial operators supported by the [Geo] injecter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoOperator {
+  Inside,
+  Outside,
+  Intersects,
+}
+
+impl GeoOperator {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      GeoOperator::Inside => "INSIDE",
+      GeoOperator::Outside => "OUTSIDE",
+      GeoOperator::Intersects => "INTERSECTS",
+    }
+  }
+}
+
+/// Compares a geometry field to a geometry using one of the geospatial operators,
+/// the geometry is expected to serialize into GeoJSON and is bound as a parameter
+/// named after the field:
+/// ```sql
+/// SELECT * FROM venue WHERE location INSIDE $location
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let area = json!({
+///   "type": "Polygon",
+///   "coordinates": [[[-0.38, 51.43], [0.12, 51.43], [0.12, 51.62], [-0.38, 51.43]]]
+/// });
+///
+/// let (query, params) = select("*", "venue", Where(Geo::inside(("location", &area)))).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM venue WHERE location INSIDE $location");
+/// assert_eq!(params.get("location"), Some(&area));
+/// ```
+pub struct Geo<T>(pub GeoOperator, pub T);
+
+impl<T> Geo<T> {
+  /// `field INSIDE $field`
+  pub fn inside(pair: T) -> Self {
+    Self(GeoOperator::Inside, pair)
+  }
+
+  /// `field OUTSIDE $field`
+  pub fn outside(pair: T) -> Self {
+    Self(GeoOperator::Outside, pair)
+  }
+
+  /// `field INTERSECTS $field`
+  pub fn intersects(pair: T) -> Self {
+    Self(GeoOperator::Intersects, pair)
+  }
+}
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for Geo<(Key, Value)>
+where
+  Key: ToNodeBuilder + Display,
+  Value: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    Cmp(self.0.as_str(), &self.1).inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    Cmp(self.0.as_str(), &self.1).params(map)
+  }
+}
+
+#[test]
+fn test_geo() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let polygon = json!({
+    "type": "Polygon",
+    "coordinates": [[[-0.38, 51.43], [0.12, 51.43], [0.12, 51.62], [-0.38, 51.43]]]
+  });
+
+  let (query, params) = select("*", "venue", Where(Geo::inside(("location", &polygon)))).unwrap();
+  assert_eq!(query, "SELECT * FROM venue WHERE location INSIDE $location");
+  assert_eq!(params.get("location"), Some(&polygon));
+
+  let (query, _) = select("*", "venue", Where(Geo::outside(("location", &polygon)))).unwrap();
+  assert_eq!(
+    query,
+    "SELECT * FROM venue WHERE location OUTSIDE $location"
+  );
+
+  let (query, params) = select(
+    "*",
+    "venue",
+    Where(Geo::intersects((
+      "area",
+      json!({ "type": "Point", "coordinates": [0.0, 51.5] }),
+    ))),
+  )
+  .unwrap();
+  assert_eq!(query, "SELECT * FROM venue WHERE area INTERSECTS $area");
+  assert_eq!(params.get("area").unwrap()["type"], json!("Point"));
+}
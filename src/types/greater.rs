@@ -9,6 +9,7 @@ use crate::prelude::ToNodeBuilder;
 use crate::queries::BindingMap;
 
 use super::ser_to_param_value;
+use super::Sql;
 
 pub struct Greater<T>(pub T);
 
@@ -65,6 +66,24 @@ where
   }
 }
 
+/// The raw SQL is written as is on the right hand side and nothing is bound,
+/// e.g `Greater(("created", Sql("time::now()")))` results in `created > time::now()`.
+impl<'a, Key, Rhs> QueryBuilderInjecter<'a> for Greater<(Key, Sql<Rhs>)>
+where
+  Key: ToNodeBuilder + Display,
+  Rhs: Display,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.0 .0.greater_than(&self.0 .1 .0.to_string()));
+
+    querybuilder
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
+  }
+}
+
 impl<'a> QueryBuilderInjecter<'a> for Greater<Value> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
     if let Some(map) = self.0.as_object() {
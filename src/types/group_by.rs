@@ -0,0 +1,16 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+pub struct GroupBy<T>(pub T);
+
+impl<'a, const N: usize> QueryBuilderInjecter<'a> for GroupBy<[&'a str; N]> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.group_by_many(&self.0)
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for GroupBy<&[&'a str]> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.group_by_many(self.0)
+  }
+}
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::merge_bindings;
+use crate::queries::rename_params;
+use crate::queries::BindingMap;
+use crate::queries::RESERVED_PARAMS;
+
+/// Checks that the key is one of the values returned by a subquery, the
+/// subquery is built from the injecters in a separate builder and its parameters
+/// are prefixed with `subquery_` so they cannot overwrite the ones of the outer
+/// query:
+/// ```sql
+/// SELECT * FROM user WHERE id IN ( SELECT VALUE author FROM post WHERE published = $subquery_published )
+/// ```
+///
+/// Two subqueries of the same query that bind the same parameter to different
+/// values result in a [BindingCollision](crate::queries::BindingCollision)
+/// error, wrap one of them in a [Prefixed](super::Prefixed) to tell them apart.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let authors = (Select("VALUE author"), From("post"), Where(("published", true)));
+/// let (query, params) = select("*", "user", Where(InSubquery(("id", authors)))).unwrap();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT * FROM user WHERE id IN ( SELECT VALUE author FROM post WHERE published = $subquery_published )"
+/// );
+/// assert!(params.contains_key("subquery_published"));
+/// ```
+pub struct InSubquery<T>(pub T);
+
+impl InSubquery<()> {
+  const PREFIX: &'static str = "subquery";
+}
+
+impl<'a, Key, Subquery> QueryBuilderInjecter<'a> for InSubquery<(Key, Subquery)>
+where
+  Key: Display,
+  Subquery: QueryBuilderInjecter<'a>,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let (key, subquery) = &self.0;
+    let subquery = subquery.inject(QueryBuilder::new()).build();
+    let subquery = rename_params(&subquery, |name| format!("{}_{name}", InSubquery::PREFIX));
+
+    querybuilder.add_segment(format!("{key} IN ( {subquery} )"));
+
+    querybuilder
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let mut inner_map = BindingMap::new();
+    self.0 .1.params(&mut inner_map)?;
+
+    let mut prefixed_map = BindingMap::new();
+    for (key, value) in inner_map {
+      match RESERVED_PARAMS.contains(&key.as_str()) {
+        true => prefixed_map.insert(key, value),
+        false => prefixed_map.insert(format!("{}_{key}", InSubquery::PREFIX), value),
+      };
+    }
+
+    *map = merge_bindings(std::mem::take(map), prefixed_map)?;
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_in_subquery() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let posts = (
+    Select("VALUE author"),
+    From("post"),
+    Where((("published", true), Greater(("likes", 100)))),
+  );
+  let filter = Where((("banned", false), InSubquery(("id", posts))));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE banned = $banned AND id IN \
+    ( SELECT VALUE author FROM post WHERE published = $subquery_published AND likes > $subquery_likes )"
+  );
+  assert_eq!(params.get("banned"), Some(&json!(false)));
+  assert_eq!(params.get("subquery_published"), Some(&json!(true)));
+  assert_eq!(params.get("subquery_likes"), Some(&json!(100)));
+}
+
+#[test]
+fn test_in_subquery_same_param() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let posts = (
+    Select("VALUE author"),
+    From("post"),
+    Where(("published", true)),
+  );
+  let filter = Where((("published", false), InSubquery(("id", posts))));
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE published = $published AND id IN \
+    ( SELECT VALUE author FROM post WHERE published = $subquery_published )"
+  );
+  assert_eq!(params.get("published"), Some(&json!(false)));
+  assert_eq!(params.get("subquery_published"), Some(&json!(true)));
+
+  // two subqueries binding the same parameter to different values
+  let drafts = (
+    Select("VALUE author"),
+    From("post"),
+    Where(("published", false)),
+  );
+  let posts = (
+    Select("VALUE author"),
+    From("post"),
+    Where(("published", true)),
+  );
+  let filter = Where((InSubquery(("id", drafts)), InSubquery(("id", posts))));
+
+  assert!(select("*", "user", filter).is_err());
+}
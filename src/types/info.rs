@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+/// Emits an `INFO FOR` statement to introspect the root, the namespace, the
+/// database, a table or a user:
+/// ```sql
+/// INFO FOR TABLE user
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// assert_eq!(Info::Db.to_string(), "INFO FOR DB");
+///
+/// let query = QueryBuilder::new().injecter(&Info::Table("user")).build();
+///
+/// assert_eq!(query, "INFO FOR TABLE user");
+/// ```
+pub enum Info<'a> {
+  Root,
+  Ns,
+  Db,
+  Table(&'a str),
+  User(&'a str),
+}
+
+impl<'a> Display for Info<'a> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Info::Root => write!(f, "INFO FOR ROOT"),
+      Info::Ns => write!(f, "INFO FOR NS"),
+      Info::Db => write!(f, "INFO FOR DB"),
+      Info::Table(table) => write!(f, "INFO FOR TABLE {table}"),
+      Info::User(user) => write!(f, "INFO FOR USER {user}"),
+    }
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for Info<'a> {
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.to_string());
+    querybuilder
+  }
+}
+
+#[test]
+fn test_info() {
+  use crate::prelude::*;
+
+  assert_eq!(Info::Root.to_string(), "INFO FOR ROOT");
+  assert_eq!(Info::Ns.to_string(), "INFO FOR NS");
+  assert_eq!(Info::Db.to_string(), "INFO FOR DB");
+  assert_eq!(Info::Table("user").to_string(), "INFO FOR TABLE user");
+  assert_eq!(Info::User("admin").to_string(), "INFO FOR USER admin");
+
+  let query = QueryBuilder::new().injecter(&Info::Ns).build();
+  assert_eq!(query, "INFO FOR NS");
+}
@@ -10,16 +10,66 @@ use crate::prelude::QueryBuilderInjecter;
 /// **Note:** If you know the limit value at compile time prefer a
 /// `&'static str` over a `u64` to avoid an unnecessary `to_string()` call.
 ///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, _) = select("*", "user", Limit(25)).unwrap();
+/// assert_eq!(query, "SELECT * FROM user LIMIT 25");
+///
+/// let page_size: u64 = 50;
+/// let (query, _) = select("*", "user", Limit::from(page_size)).unwrap();
+/// assert_eq!(query, "SELECT * FROM user LIMIT 50");
+/// ```
+///
+/// Only unsigned integers are accepted so a negative page size cannot turn into
+/// an unbounded query, signed values must be converted with `u64::try_from`
+/// first:
+/// ```compile_fail
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let page_size: i32 = -1;
+/// let (query, _) = select("*", "user", Limit(page_size)).unwrap();
+/// ```
 pub struct Limit<T>(pub T);
 
+impl From<u64> for Limit<u64> {
+  fn from(value: u64) -> Self {
+    Limit(value)
+  }
+}
+
 impl<'a> QueryBuilderInjecter<'a> for Limit<&'a str> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
     querybuilder.limit(self.0)
   }
 }
 
+impl<'a> QueryBuilderInjecter<'a> for Limit<String> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.limit(self.0.clone())
+  }
+}
+
 impl<'a> QueryBuilderInjecter<'a> for Limit<u64> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
     querybuilder.limit(self.0.to_string())
   }
 }
+
+#[test]
+fn test_limit() {
+  use crate::prelude::*;
+
+  let (query, _) = select("*", "user", Limit(10)).unwrap();
+  assert_eq!(query, "SELECT * FROM user LIMIT 10");
+
+  let (query, _) = select("*", "user", Limit(10_u64)).unwrap();
+  assert_eq!(query, "SELECT * FROM user LIMIT 10");
+
+  let (query, _) = select("*", "user", Limit("10")).unwrap();
+  assert_eq!(query, "SELECT * FROM user LIMIT 10");
+
+  let (query, _) = select("*", "user", Limit(10.to_string())).unwrap();
+  assert_eq!(query, "SELECT * FROM user LIMIT 10");
+}
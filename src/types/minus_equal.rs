@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::optional::is_none;
+use super::ser_to_param_value;
+use super::Sql;
+
+/// The counterpart of [PlusEqual](super::PlusEqual), it decrements numbers and
+/// removes items from arrays:
+/// ```sql
+/// UPDATE post:x SET stock -= $stock
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, params) = update("post:x", Set(MinusEqual(("stock", 1)))).unwrap();
+///
+/// assert_eq!(query, "UPDATE post:x SET stock -= $stock");
+/// assert_eq!(params.get("stock"), Some(&serde_json::json!(1)));
+/// ```
+pub struct MinusEqual<T>(pub T);
+
+/// Base functions for all implementations of the `QueryBuilderInjecter` trait
+impl MinusEqual<()> {
+  fn minusequal_inject<'a>(
+    mut querybuilder: QueryBuilder<'a>, key: &impl ToNodeBuilder,
+  ) -> QueryBuilder<'a> {
+    querybuilder.add_segment(key.minus_equal_parameterized());
+
+    querybuilder
+  }
+
+  fn minusequal_params(
+    map: &mut BindingMap, key: &impl ToNodeBuilder, value: impl Serialize,
+  ) -> serde_json::Result<()> {
+    map.insert(key.as_param(), ser_to_param_value(value)?);
+
+    Ok(())
+  }
+}
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for MinusEqual<&(Key, Value)>
+where
+  Key: ToNodeBuilder,
+  Value: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    match is_none(&self.0 .1) {
+      true => querybuilder,
+      false => MinusEqual::minusequal_inject(querybuilder, &self.0 .0),
+    }
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    match is_none(&self.0 .1) {
+      true => Ok(()),
+      false => MinusEqual::minusequal_params(map, &self.0 .0, &self.0 .1),
+    }
+  }
+}
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for MinusEqual<(Key, Value)>
+where
+  Key: ToNodeBuilder + Display,
+  Value: Serialize,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    MinusEqual(&self.0).inject(querybuilder)
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    MinusEqual(&self.0).params(map)
+  }
+}
+
+/// The raw SQL is written as is on the right hand side and nothing is bound,
+/// e.g `MinusEqual(("tags", Sql("$auth.tags")))` results in `tags -= $auth.tags`.
+impl<'a, Key> QueryBuilderInjecter<'a> for MinusEqual<(Key, Sql<&str>)>
+where
+  Key: ToNodeBuilder + Display,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.0 .0.minus_equal(self.0 .1 .0));
+
+    querybuilder
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for MinusEqual<Value> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    if let Some(map) = self.0.as_object() {
+      return map
+        .keys()
+        .fold(querybuilder, |q, key| MinusEqual::minusequal_inject(q, key));
+    }
+
+    querybuilder
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    self.0.params(map)
+  }
+}
+
+#[test]
+fn test_minus_equal() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let (query, params) = update(
+    "post:x",
+    Set((
+      MinusEqual(("stock", 1)),
+      MinusEqual(("tags", Sql("['draft']"))),
+      PlusEqual(("sold", 1)),
+    )),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "UPDATE post:x SET stock -= $stock , tags -= ['draft'] , sold += $sold"
+  );
+  assert_eq!(params.len(), 2);
+  assert_eq!(params.get("stock"), Some(&json!(1)));
+  assert_eq!(params.get("sold"), Some(&json!(1)));
+
+  let (query, params) = update("post:x", Set(MinusEqual(json!({ "stock": 2 })))).unwrap();
+
+  assert_eq!(query, "UPDATE post:x SET stock -= $stock");
+  assert_eq!(params.get("stock"), Some(&json!(2)));
+}
@@ -1,52 +1,123 @@
+mod agg;
 mod also;
 mod and;
+mod and_or;
+mod array_equal;
+mod before_after;
+mod between;
 mod bind;
 mod build;
 mod cmp;
+mod comment;
+mod conditions;
+mod content;
 mod create;
+mod cursor;
 mod delete;
+mod distinct;
 mod equal;
 mod ext;
 mod fetch;
 mod filter;
 mod from;
+mod geo;
 mod greater;
+mod group_by;
+mod in_subquery;
+mod info;
 mod limit;
 mod lower;
+mod minus_equal;
+mod only;
+mod optional;
 mod or;
 mod order_by;
 mod pagination;
 mod plus_equal;
+mod prefixed;
+mod projection;
+mod remove;
+mod search;
 mod select;
 mod set;
+mod set_or_content;
+mod sleep;
 mod sql;
+mod statement_order;
+mod substr_contains;
+mod surreal_value;
+mod thing;
 mod update;
+mod when;
+mod where_fn;
 
+pub use agg::Agg;
 pub use also::Also;
 pub use and::And;
+pub use and_or::AndOr;
+pub use array_equal::AllEqual;
+pub use array_equal::AnyEqual;
+pub use before_after::After;
+pub use before_after::Before;
+pub use before_after::ReferenceExpression;
+pub use between::Between;
 pub use bind::Bind;
 pub use build::Build;
+pub use build::BuildFn;
 pub use cmp::Cmp;
+pub use comment::Comment;
+pub use conditions::Conditions;
+pub use content::Content;
 pub use create::Create;
+pub use cursor::Cursor;
 pub use delete::Delete;
+pub use distinct::Distinct;
 pub use equal::Equal;
 pub use ext::*;
 pub use fetch::Fetch;
 pub use filter::Where;
 pub use from::From;
+pub use geo::Geo;
+pub use geo::GeoOperator;
 pub use greater::Greater;
+pub use group_by::GroupBy;
+pub use in_subquery::InSubquery;
+pub use info::Info;
 pub use limit::Limit;
 pub use lower::Lower;
+pub use minus_equal::MinusEqual;
+pub use only::Only;
 pub use or::Or;
 pub use order_by::OrderAsc;
 pub use order_by::OrderBy;
+pub use order_by::OrderByError;
 pub use order_by::OrderDesc;
+pub use order_by::OrderDirection;
 pub use pagination::Pagination;
 pub use plus_equal::PlusEqual;
+pub use prefixed::Prefixed;
+pub use projection::Projection;
+pub use remove::Remove;
+pub use search::Search;
 pub use select::Select;
 pub use set::Set;
+pub use set_or_content::SetOrContent;
+pub use sleep::Sleep;
 pub use sql::Sql;
+pub use statement_order::StatementOrder;
+pub use substr_contains::SubstrContains;
+#[cfg(feature = "chrono")]
+pub use surreal_value::SurrealDatetime;
+pub use surreal_value::SurrealDuration;
+#[cfg(all(feature = "surrealdb", feature = "chrono"))]
+pub(crate) use surreal_value::DATETIME_TAG;
+#[cfg(feature = "surrealdb")]
+pub(crate) use surreal_value::DURATION_TAG;
+pub use thing::TypeThing;
 pub use update::Update;
+pub use when::When;
+pub use where_fn::ConditionBuilder;
+pub use where_fn::WhereFn;
 
 pub(crate) fn to_param_value(value: serde_json::Value) -> serde_json::Result<serde_json::Value> {
   Ok(value)
@@ -58,3 +129,46 @@ pub(crate) fn to_param_value(value: serde_json::Value) -> serde_json::Result<ser
 pub fn ser_to_param_value<T: serde::Serialize>(value: T) -> serde_json::Result<serde_json::Value> {
   to_param_value(serde_json::to_value(value)?)
 }
+
+/// Format the duration the way SurrealDB expects it in statements like `SLEEP`
+/// or `TIMEOUT`, e.g `1m30s` or `250ms`.
+pub(crate) fn format_duration(duration: std::time::Duration) -> String {
+  const UNITS: [(&str, u128); 7] = [
+    ("d", 86_400_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+  ];
+
+  let mut remaining = duration.as_nanos();
+  if remaining == 0 {
+    return "0s".to_owned();
+  }
+
+  let mut output = String::new();
+  for (unit, nanos) in UNITS {
+    let amount = remaining / nanos;
+    remaining %= nanos;
+
+    if amount > 0 {
+      output.push_str(&format!("{amount}{unit}"));
+    }
+  }
+
+  output
+}
+
+#[test]
+fn test_format_duration() {
+  use std::time::Duration;
+
+  assert_eq!(format_duration(Duration::ZERO), "0s");
+  assert_eq!(format_duration(Duration::from_secs(1)), "1s");
+  assert_eq!(format_duration(Duration::from_secs(90)), "1m30s");
+  assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+  assert_eq!(format_duration(Duration::from_secs(90_061)), "1d1h1m1s");
+  assert_eq!(format_duration(Duration::from_nanos(1_500)), "1us500ns");
+}
@@ -0,0 +1,49 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+/// Adds the `ONLY` keyword between the `FROM` keyword and the table, so a single
+/// record is returned instead of an array. The injecter can be passed after the
+/// [From](super::From) one, the keyword is always inserted in the right place:
+/// ```sql
+/// SELECT * FROM ONLY user:john
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, _params) = select("*", "user:john", Only).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM ONLY user:john");
+/// ```
+pub struct Only;
+
+impl<'a> QueryBuilderInjecter<'a> for Only {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.only()
+  }
+}
+
+#[test]
+fn test_only() {
+  use crate::prelude::*;
+
+  let (query, params) = select("*", "user:john", (Where(("age", 10)), Only)).unwrap();
+
+  assert_eq!(query, "SELECT * FROM ONLY user:john WHERE age = $age");
+  assert!(params.contains_key("age"));
+
+  let (query, _) = select("*", "user:john", (Only, Only)).unwrap();
+  assert_eq!(query, "SELECT * FROM ONLY user:john");
+
+  let query = QueryBuilder::new()
+    .update("user:john")
+    .only()
+    .set("age = 10")
+    .build();
+  assert_eq!(query, "UPDATE ONLY user:john SET age = 10");
+
+  // called before any target, there is nowhere to put the keyword
+  let query = QueryBuilder::new().select("*").only().build();
+  assert_eq!(query, "SELECT *");
+}
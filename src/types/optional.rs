@@ -0,0 +1,139 @@
+use std::fmt::Error;
+
+use serde::ser::Impossible;
+use serde::Serialize;
+use serde::Serializer;
+
+/// Whether the value is an `Option::None`, which the tuple injecters like
+/// `("name", value)` use to drop the whole key/value pair.
+///
+/// The `Option<V>` values cannot get their own `(key, Option<V>)` impls as they
+/// would overlap with the `(key, V: Serialize)` ones, so the value is passed to a
+/// [NoneDetector] instead. Only a `None` is detected, any other value stops the
+/// serialization at its first call without serializing its content, so the
+/// explicit nulls like `Value::Null`, the unit structs or a `Some(None)` are
+/// still bound as `null`.
+pub(super) fn is_none(value: &impl Serialize) -> bool {
+  value.serialize(NoneDetector).is_ok()
+}
+
+/// A serializer that only succeeds for a `None`
+struct NoneDetector;
+
+macro_rules! not_none {
+  ($($method:ident($($arg:ty),*)),* $(,)?) => {
+    $(
+      fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+        Err(Error)
+      }
+    )*
+  };
+}
+
+impl Serializer for NoneDetector {
+  type Ok = ();
+  type Error = Error;
+
+  type SerializeSeq = Impossible<(), Error>;
+  type SerializeTuple = Impossible<(), Error>;
+  type SerializeTupleStruct = Impossible<(), Error>;
+  type SerializeTupleVariant = Impossible<(), Error>;
+  type SerializeMap = Impossible<(), Error>;
+  type SerializeStruct = Impossible<(), Error>;
+  type SerializeStructVariant = Impossible<(), Error>;
+
+  fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+    Ok(())
+  }
+
+  not_none!(
+    serialize_bool(bool),
+    serialize_i8(i8),
+    serialize_i16(i16),
+    serialize_i32(i32),
+    serialize_i64(i64),
+    serialize_u8(u8),
+    serialize_u16(u16),
+    serialize_u32(u32),
+    serialize_u64(u64),
+    serialize_f32(f32),
+    serialize_f64(f64),
+    serialize_char(char),
+    serialize_str(&str),
+    serialize_bytes(&[u8]),
+    serialize_unit(),
+    serialize_unit_struct(&'static str),
+    serialize_unit_variant(&'static str, u32, &'static str),
+  );
+
+  fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_newtype_struct<T: ?Sized + Serialize>(
+    self, _: &'static str, _: &T,
+  ) -> Result<Self::Ok, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_newtype_variant<T: ?Sized + Serialize>(
+    self, _: &'static str, _: u32, _: &'static str, _: &T,
+  ) -> Result<Self::Ok, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_tuple_struct(
+    self, _: &'static str, _: usize,
+  ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_tuple_variant(
+    self, _: &'static str, _: u32, _: &'static str, _: usize,
+  ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_struct(
+    self, _: &'static str, _: usize,
+  ) -> Result<Self::SerializeStruct, Self::Error> {
+    Err(Error)
+  }
+
+  fn serialize_struct_variant(
+    self, _: &'static str, _: u32, _: &'static str, _: usize,
+  ) -> Result<Self::SerializeStructVariant, Self::Error> {
+    Err(Error)
+  }
+}
+
+#[test]
+fn test_is_none() {
+  use serde_json::json;
+  use serde_json::Value;
+
+  #[derive(Serialize)]
+  struct Unit;
+
+  assert!(is_none(&None::<u32>));
+  assert!(is_none(&&None::<&str>));
+
+  assert!(!is_none(&Some(1)));
+  assert!(!is_none(&Some(None::<u32>)));
+  assert!(!is_none(&Value::Null));
+  assert!(!is_none(&Unit));
+  assert!(!is_none(&()));
+  assert!(!is_none(&json!({ "a": null })));
+}
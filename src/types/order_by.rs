@@ -6,6 +6,78 @@ pub struct OrderBy<Order, T>(Order, T);
 pub struct OrderDesc;
 pub struct OrderAsc;
 
+/// The order of an [OrderBy] that is only known at runtime, see [OrderBy::parse]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDirection {
+  Asc,
+  Desc,
+}
+
+/// The errors returned by [OrderBy::parse]
+#[derive(Debug, PartialEq, Eq)]
+pub enum OrderByError {
+  /// The input has no field name
+  EmptyField,
+
+  /// The field is not part of the allowed fields
+  UnknownField(String),
+}
+
+impl std::fmt::Display for OrderByError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      OrderByError::EmptyField => write!(f, "no field to order by"),
+      OrderByError::UnknownField(field) => write!(f, "cannot order by unknown field {field}"),
+    }
+  }
+}
+
+impl std::error::Error for OrderByError {}
+
+impl<T> OrderBy<OrderDirection, T> {
+  /// An [OrderBy] whose direction is only known at runtime
+  pub fn new(direction: OrderDirection, field: T) -> Self {
+    Self(direction, field)
+  }
+}
+
+impl<'a> OrderBy<OrderDirection, &'a str> {
+  /// Parse a sort key as it is usually received by web APIs, where a leading `-`
+  /// means a descending order: `created_at` or `-created_at`.
+  ///
+  /// The field must be one of the `allowed` fields, like the `schema::FIELDS`
+  /// generated by the `model` macro, so the user input is never written as is
+  /// in the query.
+  ///
+  /// # Example
+  /// ```
+  /// use surreal_simple_querybuilder::prelude::*;
+  ///
+  /// let order = OrderBy::parse("-created_at", &["title", "created_at"]).unwrap();
+  /// let (query, _) = select("*", "Book", order).unwrap();
+  ///
+  /// assert_eq!(query, "SELECT * FROM Book ORDER BY created_at DESC");
+  /// assert!(OrderBy::parse("-id; DELETE Book", &["title", "created_at"]).is_err());
+  /// ```
+  pub fn parse(input: &'a str, allowed: &[&str]) -> Result<Self, OrderByError> {
+    let input = input.trim();
+    let (direction, field) = match input.strip_prefix('-') {
+      Some(field) => (OrderDirection::Desc, field),
+      None => (OrderDirection::Asc, input),
+    };
+
+    if field.is_empty() {
+      return Err(OrderByError::EmptyField);
+    }
+
+    if !allowed.contains(&field) {
+      return Err(OrderByError::UnknownField(field.to_owned()));
+    }
+
+    Ok(Self(direction, field))
+  }
+}
+
 impl<T> OrderBy<OrderDesc, T> {
   pub fn desc(field: T) -> OrderBy<OrderDesc, T> {
     Self(OrderDesc, field)
@@ -28,6 +100,14 @@ impl<'a> QueryBuilderInjecter<'a> for OrderBy<OrderAsc, &'a str> {
     querybuilder.order_by_asc(self.1)
   }
 }
+impl<'a> QueryBuilderInjecter<'a> for OrderBy<OrderDirection, &'a str> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    match self.0 {
+      OrderDirection::Asc => querybuilder.order_by_asc(self.1),
+      OrderDirection::Desc => querybuilder.order_by_desc(self.1),
+    }
+  }
+}
 
 #[cfg(feature = "model")]
 use crate::model::SchemaField;
@@ -44,3 +124,30 @@ impl<'a, const N: usize> QueryBuilderInjecter<'a> for OrderBy<OrderAsc, SchemaFi
     querybuilder.order_by_asc(self.1.to_string())
   }
 }
+
+#[test]
+fn test_order_by_parse() {
+  use crate::prelude::*;
+
+  let allowed = ["title", "created_at"];
+
+  let (query, _) = select("*", "Book", OrderBy::parse("created_at", &allowed).unwrap()).unwrap();
+  assert_eq!(query, "SELECT * FROM Book ORDER BY created_at ASC");
+
+  let (query, _) = select(
+    "*",
+    "Book",
+    OrderBy::parse("-created_at", &allowed).unwrap(),
+  )
+  .unwrap();
+  assert_eq!(query, "SELECT * FROM Book ORDER BY created_at DESC");
+
+  assert_eq!(
+    OrderBy::parse("password", &allowed).err(),
+    Some(OrderByError::UnknownField("password".to_owned()))
+  );
+  assert_eq!(
+    OrderBy::parse("-", &allowed).err(),
+    Some(OrderByError::EmptyField)
+  );
+}
@@ -1,4 +1,5 @@
 use std::ops::Range;
+use std::ops::RangeFrom;
 
 use crate::prelude::QueryBuilder;
 use crate::prelude::QueryBuilderInjecter;
@@ -8,7 +9,8 @@ use crate::prelude::QueryBuilderInjecter;
 /// offers an easy way to construct a range with a given page & page size.
 ///
 /// _The START AT clause is omitted if the left side of the range is lower or
-/// equal than 0._
+/// equal than 0, and the LIMIT clause is omitted if the range is open-ended
+/// (its right side is `u64::MAX`), see [Pagination::offset_only]._
 pub struct Pagination(pub Range<u64>);
 
 impl From<Range<u64>> for Pagination {
@@ -17,6 +19,13 @@ impl From<Range<u64>> for Pagination {
   }
 }
 
+/// An open-ended range, that results in a START AT clause without any LIMIT
+impl From<RangeFrom<u64>> for Pagination {
+  fn from(value: RangeFrom<u64>) -> Self {
+    Pagination::offset_only(value.start)
+  }
+}
+
 impl Pagination {
   pub fn new(range: Range<u64>) -> Self {
     Pagination(range)
@@ -30,6 +39,23 @@ impl Pagination {
     Self::new(page * page_size..(page + 1) * page_size)
   }
 
+  /// Create a new [Pagination] that only emits a `LIMIT n` clause, the first `n`
+  /// elements.
+  pub fn limit_only(limit: u64) -> Self {
+    Self::new(0..limit)
+  }
+
+  /// Create a new [Pagination] that only emits a `START AT n` clause, every
+  /// element after the first `n` ones.
+  pub fn offset_only(offset: u64) -> Self {
+    Self::new(offset..u64::MAX)
+  }
+
+  /// Whether the range has no right side, in which case no LIMIT clause is emitted
+  pub fn is_open_ended(&self) -> bool {
+    self.0.end == u64::MAX
+  }
+
   pub fn limit(&self) -> u64 {
     self.0.end - self.0.start
   }
@@ -44,7 +70,24 @@ impl<'a> QueryBuilderInjecter<'a> for Pagination {
     let start = self.start();
 
     querybuilder
-      .limit(self.limit().to_string())
+      .if_then(!self.is_open_ended(), |q| q.limit(self.limit().to_string()))
       .if_then(start > 0, |q| q.start_at(start.to_string()))
   }
 }
+
+#[test]
+fn test_open_ended_pagination() {
+  use crate::prelude::*;
+
+  let (query, _) = select("*", "Book", Pagination::limit_only(20)).unwrap();
+  assert_eq!(query, "SELECT * FROM Book LIMIT 20");
+
+  let (query, _) = select("*", "Book", Pagination::offset_only(40)).unwrap();
+  assert_eq!(query, "SELECT * FROM Book START AT 40");
+
+  let (query, _) = select("*", "Book", Pagination::from(10..)).unwrap();
+  assert_eq!(query, "SELECT * FROM Book START AT 10");
+
+  let (query, _) = select("*", "Book", Pagination::new_page(1, 20)).unwrap();
+  assert_eq!(query, "SELECT * FROM Book LIMIT 20 START AT 20");
+}
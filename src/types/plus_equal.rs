@@ -8,8 +8,19 @@ use crate::prelude::QueryBuilderInjecter;
 use crate::prelude::ToNodeBuilder;
 use crate::queries::BindingMap;
 
+use super::optional::is_none;
 use super::ser_to_param_value;
-
+use super::Sql;
+
+/// Passing an `Option<T>` as the value skips the whole assignment on a `None`,
+/// like the tuples in a [Set](super::Set) do, while an explicit null like
+/// `Value::Null` is still assigned:
+/// ```rs
+/// let visits: Option<u32> = None;
+/// let (query, _) = update("user:john", Set((("name", "John"), PlusEqual(("visits", visits))))).unwrap();
+///
+/// assert_eq!(query, "UPDATE user:john SET name = $name");
+/// ```
 pub struct PlusEqual<T>(pub T);
 
 /// Base functions for all implementations of the `QueryBuilderInjecter` trait
@@ -37,14 +48,20 @@ where
   Value: Serialize,
 {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    PlusEqual::plusequal_inject(querybuilder, &self.0 .0)
+    match is_none(&self.0 .1) {
+      true => querybuilder,
+      false => PlusEqual::plusequal_inject(querybuilder, &self.0 .0),
+    }
   }
 
   fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
   where
     Self: Sized,
   {
-    PlusEqual::plusequal_params(map, &self.0 .0, &self.0 .1)
+    match is_none(&self.0 .1) {
+      true => Ok(()),
+      false => PlusEqual::plusequal_params(map, &self.0 .0, &self.0 .1),
+    }
   }
 }
 
@@ -65,6 +82,23 @@ where
   }
 }
 
+/// The raw SQL is written as is on the right hand side and nothing is bound,
+/// e.g `PlusEqual(("tags", Sql("$auth.tags")))` results in `tags += $auth.tags`.
+impl<'a, Key> QueryBuilderInjecter<'a> for PlusEqual<(Key, Sql<&str>)>
+where
+  Key: ToNodeBuilder + Display,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.0 .0.plus_equal(self.0 .1 .0));
+
+    querybuilder
+  }
+
+  fn params(self, _map: &mut BindingMap) -> serde_json::Result<()> {
+    Ok(())
+  }
+}
+
 impl<'a> QueryBuilderInjecter<'a> for PlusEqual<Value> {
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
     if let Some(map) = self.0.as_object() {
@@ -0,0 +1,78 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::rename_params;
+use crate::queries::BindingMap;
+use crate::queries::RESERVED_PARAMS;
+
+/// Prefixes the names of all the parameters of the inner injecter, both in the
+/// query and in the bindings, so two injecters that filter the same field can
+/// coexist without their bindings colliding:
+/// ```sql
+/// WHERE id = $id AND id = $override_id
+/// ```
+///
+/// The parameters predefined by SurrealDB like `$auth` or `$this` are left
+/// untouched.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let filter = Where((("id", "user:john"), Prefixed("other", ("id", "user:jean"))));
+/// let (query, params) = select("*", "user", filter).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM user WHERE id = $id AND id = $other_id");
+/// assert_eq!(params.get("other_id"), Some(&serde_json::json!("user:jean")));
+/// ```
+pub struct Prefixed<T>(pub &'static str, pub T);
+
+impl Prefixed<()> {
+  /// Prefix every `$param` in the segment that is not a reserved parameter
+  fn prefix_segment(prefix: &str, segment: &str) -> String {
+    rename_params(segment, |name| format!("{prefix}_{name}"))
+  }
+}
+
+impl<'a, T: QueryBuilderInjecter<'a>> QueryBuilderInjecter<'a> for Prefixed<T> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    self
+      .1
+      .inject(QueryBuilder::new())
+      .into_segments()
+      .into_iter()
+      .fold(querybuilder, |mut q, segment| {
+        match segment.contains('$') {
+          true => q.add_segment(Prefixed::prefix_segment(self.0, &segment)),
+          false => q.add_segment(segment),
+        };
+
+        q
+      })
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let mut inner_map = BindingMap::new();
+    self.1.params(&mut inner_map)?;
+
+    for (key, value) in inner_map {
+      match RESERVED_PARAMS.contains(&key.as_str()) {
+        true => map.insert(key, value),
+        false => map.insert(format!("{}_{key}", self.0), value),
+      };
+    }
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_prefix_segment() {
+  assert_eq!(
+    Prefixed::prefix_segment("p", "a = $a AND b_c > $b_c"),
+    "a = $p_a AND b_c > $p_b_c"
+  );
+  assert_eq!(
+    Prefixed::prefix_segment("p", "author = $auth.id AND $ = 1"),
+    "author = $auth.id AND $ = 1"
+  );
+}
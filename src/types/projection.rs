@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::querybuilder::CowSegment;
+
+/// A list of aliased fields meant to be used in the `what` part of SELECT
+/// statements, each pair results in a `field AS alias` projection:
+/// ```sql
+/// SELECT handle AS h , email AS e FROM Account
+/// ```
+///
+/// The fields can be the fields of a model so the renamed projections stay tied
+/// to the schema. The projection can either be injected, in which case it starts
+/// a SELECT clause, or passed to the [select()](crate::queries::select) function.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let projection = Projection::new([("handle", "h"), ("email", "e")]);
+/// assert_eq!(projection.to_string(), "handle AS h , email AS e");
+///
+/// let (query, _) = select(projection, "Account", ()).unwrap();
+/// assert_eq!(query, "SELECT handle AS h , email AS e FROM Account");
+/// ```
+pub struct Projection {
+  fields: Vec<String>,
+}
+
+impl Projection {
+  pub fn new<F: Display, A: Display>(fields: impl IntoIterator<Item = (F, A)>) -> Self {
+    Self {
+      fields: fields
+        .into_iter()
+        .map(|(field, alias)| format!("{field} AS {alias}"))
+        .collect(),
+    }
+  }
+}
+
+impl Display for Projection {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.fields.join(" , "))
+  }
+}
+
+impl<'a> From<Projection> for CowSegment<'a> {
+  fn from(value: Projection) -> Self {
+    CowSegment::Owned(value.to_string())
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for Projection {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.select(self.to_string())
+  }
+}
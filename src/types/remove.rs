@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+/// Emits a `REMOVE` statement to delete part of the schema of the database, it is
+/// the counterpart of the `DEFINE` statements generated by the models:
+/// ```sql
+/// REMOVE FIELD email ON user
+/// ```
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// assert_eq!(Remove::Table("user").to_string(), "REMOVE TABLE user");
+/// assert_eq!(
+///   Remove::Index { index: "unique_email", table: "user" }.to_string(),
+///   "REMOVE INDEX unique_email ON user"
+/// );
+///
+/// let query = QueryBuilder::new()
+///   .injecter(&Remove::Field { field: "email", table: "user" })
+///   .build();
+///
+/// assert_eq!(query, "REMOVE FIELD email ON user");
+/// ```
+pub enum Remove<'a> {
+  Table(&'a str),
+  Field { field: &'a str, table: &'a str },
+  Index { index: &'a str, table: &'a str },
+  Event { event: &'a str, table: &'a str },
+}
+
+impl<'a> Display for Remove<'a> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Remove::Table(table) => write!(f, "REMOVE TABLE {table}"),
+      Remove::Field { field, table } => write!(f, "REMOVE FIELD {field} ON {table}"),
+      Remove::Index { index, table } => write!(f, "REMOVE INDEX {index} ON {table}"),
+      Remove::Event { event, table } => write!(f, "REMOVE EVENT {event} ON {table}"),
+    }
+  }
+}
+
+impl<'a> QueryBuilderInjecter<'a> for Remove<'a> {
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.to_string());
+    querybuilder
+  }
+}
+
+#[test]
+fn test_remove() {
+  use crate::prelude::*;
+
+  assert_eq!(Remove::Table("user").to_string(), "REMOVE TABLE user");
+  assert_eq!(
+    Remove::Field {
+      field: "email",
+      table: "user"
+    }
+    .to_string(),
+    "REMOVE FIELD email ON user"
+  );
+  assert_eq!(
+    Remove::Index {
+      index: "unique_email",
+      table: "user"
+    }
+    .to_string(),
+    "REMOVE INDEX unique_email ON user"
+  );
+  assert_eq!(
+    Remove::Event {
+      event: "email_changed",
+      table: "user"
+    }
+    .to_string(),
+    "REMOVE EVENT email_changed ON user"
+  );
+
+  let query = QueryBuilder::new().injecter(&Remove::Table("user")).build();
+  assert_eq!(query, "REMOVE TABLE user");
+}
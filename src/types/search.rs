@@ -0,0 +1,114 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::prelude::escape_string;
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::Cmp;
+
+/// Full-text search of a field using the `@@` matches operator, the searched
+/// text is bound as a parameter named after the field:
+/// ```sql
+/// SELECT *, search::score(1) AS score FROM book WHERE title @1@ $title
+/// ```
+///
+/// The field must have a `SEARCH` index for the operator to work. Passing a
+/// reference number with [Search::with_ref] allows to retrieve the score and the
+/// highlights of the matches with the [Search::score] and [Search::highlight]
+/// projections.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let search = Search::new(("title", "rust")).with_ref(1);
+/// let what = format!("* , {} AS score", Search::score(1));
+/// let (query, params) = select(what, "book", (Where(search), OrderBy::desc("score"))).unwrap();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT * , search::score(1) AS score FROM book WHERE title @1@ $title ORDER BY score DESC"
+/// );
+/// assert_eq!(params.get("title"), Some(&serde_json::json!("rust")));
+/// ```
+pub struct Search<T>(pub T, pub Option<u8>);
+
+impl<T> Search<T> {
+  /// `field @@ $field`
+  pub fn new(pair: T) -> Self {
+    Self(pair, None)
+  }
+
+  /// `field @reference@ $field`, the reference is the number to pass to the
+  /// [Search::score] and [Search::highlight] functions.
+  pub fn with_ref(self, reference: u8) -> Self {
+    Self(self.0, Some(reference))
+  }
+
+  fn operator(&self) -> String {
+    match self.1 {
+      Some(reference) => format!("@{reference}@"),
+      None => "@@".to_owned(),
+    }
+  }
+}
+
+/// Base functions for the projections of the matches
+impl Search<()> {
+  /// `search::score(reference)`, the relevance score of the match
+  pub fn score(reference: u8) -> String {
+    format!("search::score({reference})")
+  }
+
+  /// `search::highlight('prefix', 'suffix', reference)`, the matched field with
+  /// the matching terms wrapped by the prefix and the suffix. Both are escaped
+  /// using [escape_string].
+  pub fn highlight(prefix: &str, suffix: &str, reference: u8) -> String {
+    let prefix = escape_string(prefix);
+    let suffix = escape_string(suffix);
+
+    format!("search::highlight({prefix}, {suffix}, {reference})")
+  }
+}
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for Search<(Key, Value)>
+where
+  Key: ToNodeBuilder + Display,
+  Value: Serialize,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.0 .0.compares_parameterized(&self.operator()));
+
+    querybuilder
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    Cmp("@@", self.0).params(map)
+  }
+}
+
+#[test]
+fn test_search() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let (query, params) = select("*", "book", Where(Search::new(("title", "rust")))).unwrap();
+  assert_eq!(query, "SELECT * FROM book WHERE title @@ $title");
+  assert_eq!(params.get("title"), Some(&json!("rust")));
+
+  assert_eq!(
+    Search::highlight("<b>", "</b>", 1),
+    "search::highlight('<b>', '</b>', 1)"
+  );
+  assert_eq!(
+    Search::highlight("<b class='x'>", "\\", 2),
+    "search::highlight('<b class=\\'x\\'>', '\\\\', 2)"
+  );
+}
@@ -1,10 +1,16 @@
 use crate::prelude::QueryBuilder;
 use crate::prelude::QueryBuilderInjecter;
+use crate::querybuilder::CowSegment;
 
-pub struct Select(pub &'static str);
+/// Starts a SELECT clause with the given projection, which can either be a
+/// `&'static str` or an owned string built at runtime.
+pub struct Select<T = &'static str>(pub T);
 
-impl<'a> QueryBuilderInjecter<'a> for Select {
+impl<'a, T> QueryBuilderInjecter<'a> for Select<T>
+where
+  T: Into<CowSegment<'a>> + Clone,
+{
   fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
-    querybuilder.select(self.0)
+    querybuilder.select(self.0.clone())
   }
 }
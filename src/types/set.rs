@@ -11,6 +11,20 @@ use crate::queries::BindingMap;
 /// assert_eq!(params.get("name"), Some(&Value::from("John".to_owned())));
 /// assert_eq!(params.get("age"), Some(&Value::from(10)));
 /// ```
+///
+/// Any serializable struct, like the partials generated by the `model` macro,
+/// can be passed through the [Build](super::Build) injecter to get one
+/// assignment per serialized field:
+/// ```rs
+/// let partial = PartialUser::new().name("John".to_owned());
+/// let (query, params) = update("user:john", Set(Build(partial))).unwrap();
+///
+/// assert_eq!("UPDATE user:john SET name = $name", query);
+/// ```
+///
+/// As with the filters, an `Option<T>` value skips the whole assignment on a
+/// `None` rather than setting the field to `null`, which is handy for partial
+/// updates built from optional fields.
 pub struct Set<T>(pub T);
 
 impl<'a, T: QueryBuilderInjecter<'a>> QueryBuilderInjecter<'a> for Set<T> {
@@ -22,3 +36,70 @@ impl<'a, T: QueryBuilderInjecter<'a>> QueryBuilderInjecter<'a> for Set<T> {
     self.0.params(map)
   }
 }
+
+#[test]
+fn test_set_raw_values() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let (query, params) = update(
+    "user:john",
+    Set((
+      ("name", "John"),
+      ("updated_at", Sql("time::now()")),
+      PlusEqual(("visits", Sql("1"))),
+    )),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "UPDATE user:john SET name = $name , updated_at = time::now() , visits += 1"
+  );
+  assert_eq!(params.len(), 1);
+  assert_eq!(params.get("name"), Some(&json!("John")));
+}
+
+#[test]
+fn test_set_optional_values() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let bio: Option<&str> = None;
+  let visits: Option<u32> = None;
+
+  let (query, params) = update(
+    "user:john",
+    Set((
+      ("bio", bio),
+      ("name", Some("John")),
+      PlusEqual(("visits", visits)),
+      PlusEqual(("score", Some(5))),
+    )),
+  )
+  .unwrap();
+
+  assert_eq!(query, "UPDATE user:john SET name = $name , score += $score");
+  assert_eq!(params.len(), 2);
+  assert_eq!(params.get("name"), Some(&json!("John")));
+  assert_eq!(params.get("score"), Some(&json!(5)));
+  assert!(!params.contains_key("bio"));
+
+  // the explicit nulls are still assigned
+  let (query, params) = update(
+    "user:john",
+    Set((
+      ("bio", serde_json::Value::Null),
+      PlusEqual(("tags", serde_json::Value::Null)),
+      MinusEqual(("badges", Some(None::<&str>))),
+    )),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "UPDATE user:john SET bio = $bio , tags += $tags , badges -= $badges"
+  );
+  assert_eq!(params.len(), 3);
+  assert!(params.values().all(|value| value.is_null()));
+}
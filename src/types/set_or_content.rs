@@ -0,0 +1,125 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+
+use super::Build;
+use super::Content;
+use super::Set;
+
+/// Picks between a [Set] and a [Content] clause depending on the shape of the
+/// serialized value:
+/// - a flat value, where no field holds an object or an array, results in a
+///   `SET field = $field , ...` clause exactly like `Set(Build(value))`.
+/// - a nested value results in a `CONTENT $content` clause, so the nested objects
+///   and the arrays are sent as is rather than being flattened field by field.
+///
+/// Keep in mind the two clauses do not behave the same way on existing records,
+/// `SET` merges the assigned fields into the record while `CONTENT` replaces the
+/// whole record. Use [Set] or [Content] directly when that distinction matters.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let (query, _) = create("User", SetOrContent(json!({ "name": "John" }))).unwrap();
+/// assert_eq!(query, "CREATE User SET name = $name");
+///
+/// let user = json!({ "name": "John", "address": { "city": "Paris" } });
+/// let (query, _) = create("User", SetOrContent(user)).unwrap();
+/// assert_eq!(query, "CREATE User CONTENT $content");
+/// ```
+pub struct SetOrContent<T>(pub T);
+
+impl<T: Serialize> SetOrContent<T> {
+  /// Whether the value holds nested objects or arrays, a value that fails to
+  /// serialize is considered nested so the error is returned by the [Content]
+  /// injecter.
+  pub fn is_nested(&self) -> bool {
+    match serde_json::to_value(&self.0) {
+      Ok(Value::Object(fields)) => fields
+        .values()
+        .any(|value| value.is_object() || value.is_array()),
+      Ok(_) => false,
+      Err(_) => true,
+    }
+  }
+}
+
+impl<'a, T: Serialize> QueryBuilderInjecter<'a> for SetOrContent<T> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    match self.is_nested() {
+      true => Content(&self.0).inject(querybuilder),
+      false => Set(Build(&self.0)).inject(querybuilder),
+    }
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    match self.is_nested() {
+      true => Content(self.0).params(map),
+      false => Set(Build(self.0)).params(map),
+    }
+  }
+}
+
+#[test]
+fn test_set_or_content() {
+  use crate::prelude::*;
+  use serde::Serialize;
+  use serde_json::json;
+
+  #[derive(Serialize)]
+  struct Address {
+    city: &'static str,
+  }
+
+  #[derive(Serialize)]
+  struct User {
+    name: &'static str,
+    age: u8,
+  }
+
+  #[derive(Serialize)]
+  struct UserWithAddresses {
+    name: &'static str,
+    addresses: Vec<Address>,
+  }
+
+  #[derive(Serialize)]
+  struct UserWithTags {
+    name: &'static str,
+    tags: Vec<&'static str>,
+  }
+
+  let flat = User {
+    name: "John",
+    age: 20,
+  };
+  let (query, params) = create("User", SetOrContent(flat)).unwrap();
+
+  assert_eq!(query, "CREATE User SET name = $name , age = $age");
+  assert_eq!(params.get("age"), Some(&json!(20)));
+
+  let nested = UserWithAddresses {
+    name: "John",
+    addresses: vec![Address { city: "Paris" }],
+  };
+  let (query, params) = create("User", SetOrContent(nested)).unwrap();
+
+  assert_eq!(query, "CREATE User CONTENT $content");
+  assert_eq!(
+    params.get("content"),
+    Some(&json!({ "name": "John", "addresses": [{ "city": "Paris" }] }))
+  );
+
+  let tagged = UserWithTags {
+    name: "John",
+    tags: vec!["admin"],
+  };
+  let (query, _) = create("User", SetOrContent(tagged)).unwrap();
+
+  assert_eq!(query, "CREATE User CONTENT $content");
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+
+use super::format_duration;
+
+/// Emits a `SLEEP` statement that pauses the query for the given duration, it
+/// has no bindings:
+/// ```sql
+/// SLEEP 1s
+/// ```
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let query = QueryBuilder::new()
+///   .injecter(&Sleep(Duration::from_millis(1500)))
+///   .build();
+///
+/// assert_eq!(query, "SLEEP 1s500ms");
+/// ```
+pub struct Sleep(pub Duration);
+
+impl<'a> QueryBuilderInjecter<'a> for Sleep {
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(format!("SLEEP {}", format_duration(self.0)));
+    querybuilder
+  }
+}
+
+#[test]
+fn test_sleep() {
+  use crate::prelude::*;
+
+  let query = QueryBuilder::new()
+    .injecter(&Sleep(Duration::from_secs(1)))
+    .build();
+  assert_eq!(query, "SLEEP 1s");
+
+  let query = QueryBuilder::new()
+    .injecter(&Sleep(Duration::from_secs(120)))
+    .build();
+  assert_eq!(query, "SLEEP 2m");
+
+  let params = bindings(Sleep(Duration::from_secs(1))).unwrap();
+  assert!(params.is_empty());
+}
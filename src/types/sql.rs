@@ -18,3 +18,27 @@ impl<'a> QueryBuilderInjecter<'a> for Sql<&'a str> {
     Ok(())
   }
 }
+
+#[test]
+fn test_sql_right_hand_side() {
+  use crate::prelude::*;
+
+  let filter = Where(Greater(("created", Sql("time::now()"))));
+  let (query, params) = select("*", "Book", filter).unwrap();
+
+  assert_eq!(query, "SELECT * FROM Book WHERE created > time::now()");
+  assert!(params.is_empty());
+
+  let filter = Where((
+    Lower(("created", Sql("time::now()"))),
+    Cmp(">=", ("updated", Sql("created"))),
+    Equal(("author", Sql("$auth.id"))),
+  ));
+  let (query, params) = select("*", "Book", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE created < time::now() AND updated >= created AND author = $auth.id"
+  );
+  assert!(params.is_empty());
+}
@@ -0,0 +1,164 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+use crate::querybuilder::CowSegment;
+
+/// The clause keywords in the order SurrealDB expects them, a clause keyword
+/// that is not in this list stays in the clause that precedes it.
+const CLAUSE_ORDER: &[&[&str]] = &[
+  &["SELECT", "CREATE", "UPDATE", "DELETE", "RELATE"],
+  &["FROM"],
+  &["WITH"],
+  &["SET", "CONTENT", "MERGE"],
+  &["WHERE"],
+  &["SPLIT"],
+  &["GROUP BY", "GROUP ALL"],
+  &["ORDER BY"],
+  &["LIMIT"],
+  &["START AT"],
+  &["FETCH"],
+  &["TIMEOUT"],
+  &["PARALLEL"],
+];
+
+/// Reorders the clauses added by the inner injecter into the order SurrealDB
+/// expects them, regardless of how the injecters were arranged:
+/// ```sql
+/// FROM -> WITH -> WHERE -> SPLIT -> GROUP BY -> ORDER BY -> LIMIT -> START AT -> FETCH -> TIMEOUT -> PARALLEL
+/// ```
+///
+/// The inner injecter is injected into a separate buffer that is then split at
+/// every clause keyword, the clauses are finally emitted in the correct sequence.
+/// Clauses of the same kind keep their relative order, and the keywords inside
+/// parenthesis (subqueries) are left untouched.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let (query, _) = select("*", "Book", StatementOrder((
+///   Fetch(["author"]),
+///   Limit("10"),
+///   Where(("title", "Lorem Ipsum")),
+/// )))
+/// .unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM Book WHERE title = $title LIMIT 10 FETCH author");
+/// ```
+pub struct StatementOrder<T>(pub T);
+
+impl StatementOrder<()> {
+  /// The position of the clause the segment starts, if it is a clause keyword
+  fn clause_rank(segment: &str) -> Option<usize> {
+    CLAUSE_ORDER
+      .iter()
+      .position(|keywords| keywords.contains(&segment))
+  }
+
+  /// Whether one of the `keywords` starts a clause in the segments, the keywords
+  /// inside parenthesis (subqueries) are ignored.
+  pub(crate) fn has_clause(segments: &[CowSegment], keywords: &[&str]) -> bool {
+    let mut depth = 0isize;
+
+    segments.iter().any(|segment| {
+      let is_clause = depth == 0 && keywords.contains(&segment.as_ref());
+
+      depth += segment.matches('(').count() as isize;
+      depth -= segment.matches(')').count() as isize;
+
+      is_clause
+    })
+  }
+
+  /// Split the segments into `(rank, clause)` pairs, the segments that come
+  /// before the first clause keyword are considered part of the first clause.
+  fn split_clauses(segments: Vec<CowSegment>) -> Vec<(usize, Vec<CowSegment>)> {
+    let mut clauses: Vec<(usize, Vec<CowSegment>)> = Vec::new();
+    let mut depth = 0isize;
+
+    for segment in segments {
+      let rank = match depth {
+        0 => StatementOrder::clause_rank(&segment),
+        _ => None,
+      };
+
+      depth += segment.matches('(').count() as isize;
+      depth -= segment.matches(')').count() as isize;
+
+      match (rank, clauses.last_mut()) {
+        (None, Some((_, clause))) => clause.push(segment),
+        (rank, _) => clauses.push((rank.unwrap_or(0), vec![segment])),
+      };
+    }
+
+    clauses
+  }
+}
+
+impl<'a, T: QueryBuilderInjecter<'a>> QueryBuilderInjecter<'a> for StatementOrder<T> {
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let segments = self.0.inject(QueryBuilder::new()).into_segments();
+    let mut clauses = StatementOrder::split_clauses(segments);
+
+    // a stable sort, so the clauses of the same kind keep their order
+    clauses.sort_by_key(|(rank, _)| *rank);
+
+    clauses
+      .into_iter()
+      .flat_map(|(_, clause)| clause)
+      .fold(querybuilder, |mut q, segment| {
+        q.add_segment(segment);
+        q
+      })
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    self.0.params(map)
+  }
+}
+
+#[test]
+fn test_statement_order() {
+  use crate::prelude::*;
+
+  let (query, params) = select(
+    "*",
+    "Book",
+    StatementOrder((
+      Fetch(["author", "readers"]),
+      OrderBy::desc("created"),
+      Limit("10"),
+      Where(("title", "Lorem Ipsum")),
+    )),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE title = $title ORDER BY created DESC LIMIT 10 FETCH author , readers"
+  );
+  assert!(params.contains_key("title"));
+
+  // the keywords of the subqueries are not moved
+  let (query, _) = select(
+    "*",
+    "Book",
+    StatementOrder((
+      Limit("5"),
+      BuildFn(|q| {
+        q.filter("author IN")
+          .raw("(")
+          .select("id")
+          .from("Account")
+          .limit("1")
+          .raw(")")
+      }),
+    )),
+  )
+  .unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE author IN ( SELECT id FROM Account LIMIT 1 ) LIMIT 5"
+  );
+}
@@ -0,0 +1,57 @@
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+
+/// Add a substring condition on a string field, the needle is bound as a
+/// parameter named after the field.
+///
+/// # Example
+/// ```rs
+/// let filter = Where(SubstrContains("name", "oh"));
+/// let (query, params) = select("*", "User", filter).unwrap();
+///
+/// assert_eq!("SELECT * FROM User WHERE string::contains(name, $name)", query);
+/// ```
+pub struct SubstrContains<Key, Value>(pub Key, pub Value);
+
+impl<'a, Key, Value> QueryBuilderInjecter<'a> for SubstrContains<Key, Value>
+where
+  Key: ToNodeBuilder,
+  Value: Serialize,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    querybuilder.add_segment(self.0.substr_contains_parameterized());
+
+    querybuilder
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    map.insert(self.0.as_param(), ser_to_param_value(self.1)?);
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_substr_contains() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let filter = Where((SubstrContains("name", "oh"), ("age", 10)));
+  let (query, params) = select("*", "User", filter).unwrap();
+
+  assert_eq!(
+    "SELECT * FROM User WHERE string::contains(name, $name) AND age = $age",
+    query
+  );
+  assert_eq!(params.get("name"), Some(&Value::from("oh")));
+  assert_eq!(params.get("age"), Some(&Value::from(10)));
+}
@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+use serde::ser::SerializeMap;
+use serde::Serialize;
+use serde::Serializer;
+
+use super::format_duration;
+
+/// The key of the object a [SurrealDuration] serializes into.
+pub(crate) const DURATION_TAG: &str = "$surreal_duration";
+
+/// The key of the object a [SurrealDatetime] serializes into.
+#[cfg(feature = "chrono")]
+pub(crate) const DATETIME_TAG: &str = "$surreal_datetime";
+
+/// Wraps a [std::time::Duration] so it is bound as a SurrealDB duration rather
+/// than as the `{ "secs": 90, "nanos": 0 }` object serde gives it.
+///
+/// The wrapper serializes into `{ "$surreal_duration": "1m30s" }`, which the
+/// [bind_surreal](crate::queries::bind_surreal) function turns back into a
+/// native SurrealDB duration. It can be used anywhere a serializable value is
+/// expected, including in the fields of a struct passed to [Build](super::Build)
+/// where it is kept as a single `field = $field` pair.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use surreal_simple_querybuilder::prelude::*;
+/// use serde_json::json;
+///
+/// let timeout = SurrealDuration(Duration::from_secs(90));
+/// let params = bindings(Where(("timeout", timeout))).unwrap();
+///
+/// assert_eq!(
+///   params.get("timeout"),
+///   Some(&json!({ "$surreal_duration": "1m30s" }))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurrealDuration(pub Duration);
+
+impl Serialize for SurrealDuration {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(DURATION_TAG, &format_duration(self.0))?;
+    map.end()
+  }
+}
+
+impl From<Duration> for SurrealDuration {
+  fn from(value: Duration) -> Self {
+    Self(value)
+  }
+}
+
+/// Wraps a `chrono::DateTime<Utc>` so it is bound as a SurrealDB datetime rather
+/// than as a plain string, behind the `chrono` feature.
+///
+/// The wrapper serializes into `{ "$surreal_datetime": "2023-01-01T00:00:00Z" }`,
+/// which the [bind_surreal](crate::queries::bind_surreal) function turns back
+/// into a native SurrealDB datetime.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurrealDatetime(pub chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "chrono")]
+impl Serialize for SurrealDatetime {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let datetime = self.0.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true);
+
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(DATETIME_TAG, &datetime)?;
+    map.end()
+  }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for SurrealDatetime {
+  fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+    Self(value)
+  }
+}
+
+#[test]
+fn test_surreal_duration() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let duration = SurrealDuration(Duration::from_millis(1500));
+  let params = bindings(Where((("timeout", duration), Greater(("age", 18))))).unwrap();
+
+  assert_eq!(
+    params.get("timeout"),
+    Some(&json!({ "$surreal_duration": "1s500ms" }))
+  );
+  assert_eq!(params.get("age"), Some(&json!(18)));
+
+  // plain durations keep the form serde gives them
+  let params = bindings(Where(("timeout", Duration::from_secs(1)))).unwrap();
+  assert_eq!(
+    params.get("timeout"),
+    Some(&json!({ "secs": 1, "nanos": 0 }))
+  );
+}
+
+#[test]
+fn test_surreal_duration_struct_field() {
+  use crate::prelude::*;
+  use serde::Serialize;
+  use serde_json::json;
+
+  #[derive(Serialize)]
+  struct Settings {
+    name: &'static str,
+    timeout: SurrealDuration,
+  }
+
+  let settings = Settings {
+    name: "job",
+    timeout: SurrealDuration(Duration::from_secs(90)),
+  };
+  let (query, params) = update("Job", Set(Build(settings))).unwrap();
+
+  assert_eq!(query, "UPDATE Job SET name = $name , timeout = $timeout");
+  assert_eq!(
+    params.get("timeout"),
+    Some(&json!({ "$surreal_duration": "1m30s" }))
+  );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_surreal_datetime() {
+  use crate::prelude::*;
+  use chrono::TimeZone;
+  use chrono::Utc;
+  use serde_json::json;
+
+  let created = SurrealDatetime(Utc.with_ymd_and_hms(2023, 1, 2, 3, 4, 5).unwrap());
+  let params = bindings(Where(Greater(("created", created)))).unwrap();
+
+  assert_eq!(
+    params.get("created"),
+    Some(&json!({ "$surreal_datetime": "2023-01-02T03:04:05Z" }))
+  );
+}
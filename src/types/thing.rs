@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+
+/// Compares a field to a record link built with the `type::thing` function, the
+/// table and the id are bound separately so the comparison is not affected by
+/// the string vs record serialization of the record ids:
+/// ```sql
+/// author = type::thing($author_tb, $author_id)
+/// ```
+///
+/// The type is named after the `type::thing` function rather than `Thing` to not
+/// collide with the `Thing` type of the official client.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let filter = Where(TypeThing(("author", "User", "john")));
+/// let (query, params) = select("*", "Book", filter).unwrap();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT * FROM Book WHERE author = type::thing($author_tb, $author_id)"
+/// );
+/// ```
+pub struct TypeThing<T>(pub T);
+
+impl<'a, Key, Table, Id> QueryBuilderInjecter<'a> for TypeThing<(Key, Table, Id)>
+where
+  Key: ToNodeBuilder,
+  Table: Serialize,
+  Id: Serialize,
+{
+  fn inject(&self, mut querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let key = &self.0 .0;
+    let param = key.as_param();
+
+    querybuilder.add_segment(format!("{key} = type::thing(${param}_tb, ${param}_id)"));
+
+    querybuilder
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()>
+  where
+    Self: Sized,
+  {
+    let (key, table, id) = self.0;
+    let param = key.as_param();
+
+    map.insert(format!("{param}_tb"), ser_to_param_value(table)?);
+    map.insert(format!("{param}_id"), ser_to_param_value(id)?);
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_thing() {
+  use crate::prelude::*;
+  use serde_json::Value;
+
+  let filter = Where((TypeThing(("author", "User", "john")), ("read", true)));
+  let (query, params) = select("*", "Book", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM Book WHERE author = type::thing($author_tb, $author_id) AND read = $read"
+  );
+  assert_eq!(params.get("author_tb"), Some(&Value::from("User")));
+  assert_eq!(params.get("author_id"), Some(&Value::from("john")));
+  assert_eq!(params.get("read"), Some(&Value::from(true)));
+}
@@ -0,0 +1,44 @@
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::queries::BindingMap;
+
+/// Injects the wrapped injecter only if the condition is true, nothing is
+/// injected nor bound otherwise. It is the same as calling
+/// [when()](super::IntoOptionalInjecterExt::when) on the injecter but reads
+/// better in long tuples of injecters.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let is_admin = false;
+/// let (query, params) = select(
+///   "*",
+///   "user",
+///   (When(is_admin, Fetch(["audit_log"])), When(true, Where(("age", 18)))),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM user WHERE age = $age");
+/// assert!(params.contains_key("age"));
+/// ```
+pub struct When<T>(pub bool, pub T);
+
+impl<'a, T> QueryBuilderInjecter<'a> for When<T>
+where
+  T: QueryBuilderInjecter<'a>,
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    match self.0 {
+      true => self.1.inject(querybuilder),
+      false => querybuilder,
+    }
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    match self.0 {
+      true => self.1.params(map),
+      false => Ok(()),
+    }
+  }
+}
@@ -0,0 +1,211 @@
+use serde::Serialize;
+
+use crate::prelude::QueryBuilder;
+use crate::prelude::QueryBuilderInjecter;
+use crate::prelude::ToNodeBuilder;
+use crate::queries::BindingMap;
+
+use super::ser_to_param_value;
+
+/// Collects the conditions and the bindings of a [WhereFn] filter. The
+/// conditions are separated by `AND`s and the parameters are named after the
+/// keys, exactly like the declarative injecters do.
+#[derive(Default)]
+pub struct ConditionBuilder {
+  conditions: Vec<String>,
+  bindings: BindingMap,
+
+  /// the first serialization error, if any, it is returned by the `params`
+  /// function of the [WhereFn] injecter.
+  error: Option<serde_json::Error>,
+}
+
+impl ConditionBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// `key = $key`
+  pub fn eq(&mut self, key: impl ToNodeBuilder, value: impl Serialize) -> &mut Self {
+    self.cmp("=", key, value)
+  }
+
+  /// `key > $key`
+  pub fn gt(&mut self, key: impl ToNodeBuilder, value: impl Serialize) -> &mut Self {
+    self.cmp(">", key, value)
+  }
+
+  /// `key < $key`
+  pub fn lt(&mut self, key: impl ToNodeBuilder, value: impl Serialize) -> &mut Self {
+    self.cmp("<", key, value)
+  }
+
+  /// `key OPERATOR $key`
+  pub fn cmp(
+    &mut self, operator: &str, key: impl ToNodeBuilder, value: impl Serialize,
+  ) -> &mut Self {
+    self.conditions.push(key.compares_parameterized(operator));
+    self.bind(key.as_param(), value)
+  }
+
+  /// Pushes the condition as is, nothing is bound.
+  pub fn raw(&mut self, condition: impl Into<String>) -> &mut Self {
+    self.conditions.push(condition.into());
+    self
+  }
+
+  /// Binds a parameter without adding a condition, for the parameters used in
+  /// the [ConditionBuilder::raw] conditions.
+  pub fn bind(&mut self, key: impl Into<String>, value: impl Serialize) -> &mut Self {
+    match ser_to_param_value(value) {
+      Ok(value) => {
+        self.bindings.insert(key.into(), value);
+      }
+      Err(error) => {
+        self.error.get_or_insert(error);
+      }
+    };
+
+    self
+  }
+
+  /// Adds a group of conditions separated by `OR`s and wrapped in parenthesis,
+  /// an empty group adds nothing.
+  pub fn or_group(&mut self, action: impl FnOnce(&mut ConditionBuilder)) -> &mut Self {
+    self.group(" OR ", action)
+  }
+
+  /// Adds a group of conditions separated by `AND`s and wrapped in parenthesis,
+  /// an empty group adds nothing.
+  pub fn and_group(&mut self, action: impl FnOnce(&mut ConditionBuilder)) -> &mut Self {
+    self.group(" AND ", action)
+  }
+
+  fn group(&mut self, separator: &str, action: impl FnOnce(&mut ConditionBuilder)) -> &mut Self {
+    let mut group = ConditionBuilder::new();
+    action(&mut group);
+
+    if !group.conditions.is_empty() {
+      self
+        .conditions
+        .push(format!("( {} )", group.conditions.join(separator)));
+    }
+
+    self.bindings.extend(group.bindings);
+    if let Some(error) = group.error {
+      self.error.get_or_insert(error);
+    }
+
+    self
+  }
+}
+
+/// Add a WHERE clause to the query whose conditions are built imperatively by
+/// the function, for the filters whose logic is too branchy for tuples of
+/// injecters. It results in the same query as the equivalent [Where](super::Where)
+/// filter.
+///
+/// The function is called once for the query and once for the bindings, so it
+/// should build the same conditions every time.
+///
+/// # Example
+/// ```
+/// use surreal_simple_querybuilder::prelude::*;
+///
+/// let minimum_age = Some(18);
+/// let filter = WhereFn(|c: &mut ConditionBuilder| {
+///   c.eq("name", "John");
+///
+///   if let Some(age) = minimum_age {
+///     c.gt("age", age);
+///   }
+/// });
+///
+/// let (query, params) = select("*", "user", filter).unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM user WHERE name = $name AND age > $age");
+/// assert_eq!(params.len(), 2);
+/// ```
+pub struct WhereFn<F>(pub F)
+where
+  F: Fn(&mut ConditionBuilder);
+
+impl<F> WhereFn<F>
+where
+  F: Fn(&mut ConditionBuilder),
+{
+  fn build(&self) -> ConditionBuilder {
+    let mut builder = ConditionBuilder::new();
+    self.0(&mut builder);
+
+    builder
+  }
+}
+
+impl<'a, F> QueryBuilderInjecter<'a> for WhereFn<F>
+where
+  F: Fn(&mut ConditionBuilder),
+{
+  fn inject(&self, querybuilder: QueryBuilder<'a>) -> QueryBuilder<'a> {
+    let conditions = self.build().conditions;
+
+    if conditions.is_empty() {
+      return querybuilder;
+    }
+
+    querybuilder.filter("").ands(|mut q| {
+      for condition in &conditions {
+        q.add_segment(condition.clone());
+      }
+
+      q
+    })
+  }
+
+  fn params(self, map: &mut BindingMap) -> serde_json::Result<()> {
+    let builder = self.build();
+
+    if let Some(error) = builder.error {
+      return Err(error);
+    }
+
+    map.extend(builder.bindings);
+
+    Ok(())
+  }
+}
+
+#[test]
+fn test_where_fn() {
+  use crate::prelude::*;
+  use serde_json::json;
+
+  let declarative = Where((
+    ("name", "John"),
+    Greater(("age", 18)),
+    BuildFn(|q| q.ors_group(|q| q.raw("role = $role").raw("admin = true"))),
+    Bind(("role", "editor")),
+  ));
+  let (expected_query, expected_params) = select("*", "user", declarative).unwrap();
+
+  let filter = WhereFn(|c: &mut ConditionBuilder| {
+    c.eq("name", "John").gt("age", 18).or_group(|c| {
+      c.raw("role = $role")
+        .bind("role", "editor")
+        .raw("admin = true");
+    });
+  });
+  let (query, params) = select("*", "user", filter).unwrap();
+
+  assert_eq!(
+    query,
+    "SELECT * FROM user WHERE name = $name AND age > $age AND ( role = $role OR admin = true )"
+  );
+  assert_eq!(query, expected_query);
+  assert_eq!(params, expected_params);
+  assert_eq!(params.get("role"), Some(&json!("editor")));
+
+  let (query, params) = select("*", "user", WhereFn(|_: &mut ConditionBuilder| {})).unwrap();
+  assert_eq!(query, "SELECT * FROM user");
+  assert!(params.is_empty());
+}
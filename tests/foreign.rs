@@ -95,3 +95,115 @@ fn foreign_key_impl_clone() {
 
   assert_eq!(original, cloned);
 }
+
+#[test]
+#[cfg(feature = "foreign")]
+fn foreign_key_try_into_value() {
+  use surreal_simple_querybuilder::prelude::*;
+
+  let loaded: Foreign<&str> = Foreign::new_value("John");
+  let key: Foreign<&str> = Foreign::new_key("user:john".to_owned());
+  let unloaded: Foreign<&str> = Foreign::new();
+
+  assert_eq!(loaded.clone().try_into_value(), Ok("John"));
+  assert_eq!(key.try_into_value(), Err(ForeignKeyError::WasKey));
+  assert_eq!(unloaded.try_into_value(), Err(ForeignKeyError::WasUnloaded));
+  assert_eq!(loaded.expect_value("the author is fetched"), "John");
+
+  assert_eq!(
+    ForeignKeyError::WasUnloaded.to_string(),
+    "the foreign key is unloaded, the field was probably null or not selected"
+  );
+}
+
+#[test]
+#[cfg(feature = "foreign")]
+fn foreign_key_zip() {
+  use surreal_simple_querybuilder::prelude::*;
+
+  let loaded = || Foreign::<&str>::new_value("John");
+  let key = || Foreign::<&str>::new_key("user:john".to_owned());
+  let unloaded = || Foreign::<&str>::new();
+
+  assert_eq!(loaded().zip(loaded()).value(), Some(&("John", "John")));
+  assert_eq!(
+    key().zip(key()).key(),
+    Some(&("user:john".to_owned(), "user:john".to_owned()))
+  );
+
+  assert!(loaded().zip(key()).is_unloaded());
+  assert!(key().zip(loaded()).is_unloaded());
+  assert!(loaded().zip(unloaded()).is_unloaded());
+  assert!(unloaded().zip(key()).is_unloaded());
+}
+
+#[test]
+#[cfg(feature = "foreign")]
+#[should_panic(
+  expected = "the author is fetched: the foreign key holds a key, the field was probably not fetched"
+)]
+fn foreign_key_expect_value() {
+  use surreal_simple_querybuilder::prelude::*;
+
+  let key: Foreign<&str> = Foreign::new_key("user:john".to_owned());
+
+  key.expect_value("the author is fetched");
+}
+
+#[test]
+#[cfg(feature = "foreign")]
+fn foreign_key_resolve_from() {
+  use std::collections::HashMap;
+  use surreal_simple_querybuilder::prelude::*;
+
+  let users = HashMap::from([
+    ("user:john".to_owned(), "John"),
+    ("user:jean".to_owned(), "Jean"),
+  ]);
+
+  let mut authors: Vec<Foreign<&str>> = vec![
+    Foreign::new_key("user:john".to_owned()),
+    Foreign::new_key("user:unknown".to_owned()),
+    Foreign::new_value("Jane"),
+    Foreign::new(),
+    Foreign::new_key("user:jean".to_owned()),
+  ];
+
+  authors.resolve_from(&users);
+
+  assert_eq!(authors[0].value(), Some(&"John"));
+  assert_eq!(authors[1].key(), Some(&"user:unknown".to_owned()));
+  assert_eq!(authors[2].value(), Some(&"Jane"));
+  assert!(authors[3].is_unloaded());
+  assert_eq!(authors[4].value(), Some(&"Jean"));
+}
+
+#[test]
+#[cfg(feature = "foreign")]
+fn foreign_key_display() {
+  use surreal_simple_querybuilder::prelude::*;
+
+  struct User {
+    id: Option<String>,
+  }
+
+  impl IntoKey<String> for User {
+    fn into_key(&self) -> Result<String, IntoKeyError> {
+      self.id.clone().ok_or(IntoKeyError::MissingId)
+    }
+  }
+
+  let key: Foreign<User> = Foreign::new_key("user:john".to_owned());
+  assert_eq!(key.to_string(), "user:john");
+
+  let loaded: Foreign<User> = Foreign::new_value(User {
+    id: Some("user:jean".to_owned()),
+  });
+  assert_eq!(format!("author: {loaded}"), "author: user:jean");
+
+  let loaded_without_id: Foreign<User> = Foreign::new_value(User { id: None });
+  assert_eq!(loaded_without_id.to_string(), "<loaded>");
+
+  let unloaded: Foreign<User> = Foreign::new();
+  assert_eq!(unloaded.to_string(), "<unloaded>");
+}
@@ -28,6 +28,7 @@ mod two {
   fn test_string_literal() {
     assert_eq!(schema::model.r#in.to_string(), "in");
     assert_eq!(schema::model.r#for.to_string(), "->relation->TestModel0");
+    assert_eq!(schema::FIELDS, &["id", "in", "other", "for"]);
 
     assert_eq!(
       serde_json::to_string(&schema::model.r#in).unwrap(),
@@ -46,3 +47,715 @@ mod two {
     );
   }
 }
+
+mod three {
+  surreal_simple_querybuilder::model!(Account with(data) {
+    id: Option<String>,
+    pub handle: String,
+    #[doc = "The age of the account"]
+    pub age: u8,
+    pub friends<Account>: Vec<String>,
+    pub tags: std::collections::HashMap<String, Vec<String>>,
+    pub nicknames: [String],
+    untyped,
+  });
+
+  #[test]
+  fn test_data_struct() {
+    let mut data = schema::AccountData::default();
+
+    assert_eq!(data.id, None);
+    assert_eq!(data.handle, "");
+
+    data.handle = "John".to_owned();
+    data.age = 20;
+    data.friends.push("Account:Mark".to_owned());
+    data.nicknames.push("Johnny".to_owned());
+
+    assert_eq!(
+      serde_json::to_value(&data).unwrap(),
+      serde_json::json!({
+        "id": null,
+        "handle": "John",
+        "age": 20,
+        "friends": ["Account:Mark"],
+        "tags": {},
+        "nicknames": ["Johnny"]
+      })
+    );
+  }
+}
+
+mod four {
+  use super::three::schema::Account;
+
+  surreal_simple_querybuilder::model!(Book with(schema) {
+    id: Option<String>,
+    pub title: String,
+    pub pages: u32,
+    pub rating: Option<f32>,
+    pub author<Account>,
+    pub readers<Account>: Vec<String>,
+    pub tags: Vec<String>,
+    pub extra,
+    ->similar->Book as similar_books,
+  });
+
+  #[test]
+  fn test_nested_field_filter() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    let filter = Where((book.author().handle, "John"));
+    let (query, params) = select("*", "Book", filter).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM Book WHERE author.handle = $author_handle"
+    );
+    assert_eq!(
+      params.get("author_handle"),
+      Some(&serde_json::to_value("John").unwrap())
+    );
+  }
+
+  #[test]
+  fn test_field_segments() {
+    use schema::model as book;
+
+    let handle = book.author().handle;
+
+    assert_eq!(handle.segments(), ["author", "handle"]);
+    assert_eq!(book.title.segments(), ["title"]);
+    assert_eq!(book.similar_books.segments(), ["similar->Book"]);
+  }
+
+  #[test]
+  fn test_scored_search() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    let what = format!(
+      "* , {} AS score , {} AS snippet",
+      Search::score(1),
+      Search::highlight("<b>", "</b>", 1)
+    );
+    let search = Search::new((book.title, "rust")).with_ref(1);
+    let (query, params) = select(what, &book, (Where(search), OrderBy::desc("score"))).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * , search::score(1) AS score , search::highlight('<b>', '</b>', 1) AS snippet \
+      FROM Book WHERE title @1@ $title ORDER BY score DESC"
+    );
+    assert_eq!(params.get("title"), Some(&serde_json::json!("rust")));
+  }
+
+  #[test]
+  fn test_range_field_filter() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    let (query, params) = select("*", &book, Where((book.pages, Between(100..300)))).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM Book WHERE pages >= $pages_min AND pages < $pages_max"
+    );
+    assert_eq!(params.get("pages_min"), Some(&serde_json::json!(100)));
+    assert_eq!(params.get("pages_max"), Some(&serde_json::json!(300)));
+    assert_eq!(params.get("pages"), None);
+  }
+
+  #[test]
+  fn test_inclusive_range_field_filter() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    let filter = Where(((book.title, "Dune"), (book.rating, Between(2.5..=5.0))));
+    let (query, params) = select("*", &book, filter).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM Book WHERE title = $title AND rating >= $rating_min AND rating <= $rating_max"
+    );
+    assert_eq!(params.get("rating_min"), Some(&serde_json::json!(2.5)));
+    assert_eq!(params.get("rating_max"), Some(&serde_json::json!(5.0)));
+  }
+
+  #[test]
+  fn test_array_equal_field_filter() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    let (query, params) = select("*", "Book", Where(AllEqual((book.tags, "rust")))).unwrap();
+
+    assert_eq!(query, "SELECT * FROM Book WHERE tags *= $tags");
+    assert_eq!(params.get("tags"), Some(&serde_json::json!("rust")));
+
+    let (query, params) = select("*", "Book", Where(AnyEqual((book.tags, "rust")))).unwrap();
+
+    assert_eq!(query, "SELECT * FROM Book WHERE tags ?= $tags");
+    assert_eq!(params.get("tags"), Some(&serde_json::json!("rust")));
+  }
+
+  #[test]
+  fn test_array_element_filter() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    assert_eq!(
+      book.tags.array_filter("$this = 'x'"),
+      "tags[WHERE $this = 'x']"
+    );
+    assert_eq!(
+      book.author().handle.array_filter("$this != NONE"),
+      "author.handle[WHERE $this != NONE]"
+    );
+  }
+
+  #[test]
+  fn test_cmp_field_filter() {
+    use schema::model as book;
+    use surreal_simple_querybuilder::prelude::*;
+
+    let filter = Where(Cmp(">=", (book.pages, 5)));
+    let (query, params) = select("*", "Book", filter).unwrap();
+
+    assert_eq!(query, "SELECT * FROM Book WHERE pages >= $pages");
+    assert_eq!(params.get("pages"), Some(&serde_json::json!(5)));
+
+    let filter = Where(Cmp("!=", (book.author().handle, "John")));
+    let (query, params) = select("*", "Book", filter).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM Book WHERE author.handle != $author_handle"
+    );
+    assert_eq!(
+      params.get("author_handle"),
+      Some(&serde_json::json!("John"))
+    );
+  }
+
+  #[test]
+  fn test_aggregate_projections() {
+    use schema::model as book;
+
+    assert_eq!(book.pages.count_alias("n"), "count(pages) AS n");
+    assert_eq!(book.pages.sum_alias("total"), "math::sum(pages) AS total");
+    assert_eq!(
+      book.rating.mean_alias("average"),
+      "math::mean(rating) AS average"
+    );
+    assert_eq!(
+      book.pages.min_alias("shortest"),
+      "math::min(pages) AS shortest"
+    );
+    assert_eq!(
+      book.pages.max_alias("longest"),
+      "math::max(pages) AS longest"
+    );
+    assert_eq!(
+      book.author().age.sum_alias("ages"),
+      "math::sum(author.age) AS ages"
+    );
+  }
+
+  #[test]
+  fn test_define_statements() {
+    assert_eq!(
+      schema::define(),
+      "DEFINE TABLE Book; \
+      DEFINE FIELD title ON Book TYPE string; \
+      DEFINE FIELD pages ON Book TYPE int; \
+      DEFINE FIELD rating ON Book TYPE option<float>; \
+      DEFINE FIELD author ON Book TYPE record<Account>; \
+      DEFINE FIELD readers ON Book TYPE array<record<Account>>; \
+      DEFINE FIELD tags ON Book TYPE array<string>; \
+      DEFINE FIELD extra ON Book;"
+    );
+  }
+}
+
+mod five {
+  use super::three::schema::Account;
+
+  surreal_simple_querybuilder::model!(Profile with(data, schema) {
+    id,
+    pub created_at as "createdAt": String,
+    pub owner<Account> as "ownerId",
+  });
+
+  #[test]
+  fn test_field_rename() {
+    use schema::model as profile;
+
+    assert_eq!(profile.created_at.to_string(), "createdAt");
+    assert_eq!(profile.owner.to_string(), "ownerId");
+    assert_eq!(profile.owner().handle.to_string(), "ownerId.handle");
+
+    let data = schema::ProfileData {
+      created_at: "today".to_owned(),
+    };
+
+    assert_eq!(
+      serde_json::to_value(&data).unwrap(),
+      serde_json::json!({ "createdAt": "today" })
+    );
+
+    assert_eq!(
+      schema::define(),
+      "DEFINE TABLE Profile; \
+      DEFINE FIELD createdAt ON Profile TYPE string; \
+      DEFINE FIELD ownerId ON Profile TYPE record<Account>;"
+    );
+  }
+}
+
+mod six {
+  use surreal_simple_querybuilder::prelude::*;
+
+  surreal_simple_querybuilder::model!(Member with(schema) {
+    id,
+    pub handle,
+    pub friends<[Member]>,
+  });
+
+  #[test]
+  fn test_as_record() {
+    use schema::model as member;
+
+    assert_eq!(member.id.as_record("john"), "Member:john");
+    assert_eq!(member.friends().id.as_record("mark"), "Member:mark");
+    assert_eq!(schema::id("john"), "Member:john");
+    assert_eq!(schema::id("john"), member.with_id("john"));
+  }
+
+  #[test]
+  fn test_fields_const() {
+    assert_eq!(schema::FIELDS, &["id", "handle", "friends"]);
+
+    let query = QueryBuilder::new()
+      .select_many(schema::FIELDS)
+      .from(schema::model)
+      .build();
+
+    assert_eq!(query, "SELECT id , handle , friends FROM Member");
+  }
+
+  #[test]
+  fn test_foreign_array() {
+    use schema::model as member;
+
+    assert_eq!(member.friends.to_string(), "friends");
+    assert_eq!(member.friends().handle.to_string(), "friends.handle");
+    assert_eq!(
+      member.friends().friends().handle.to_string(),
+      "friends.friends.handle"
+    );
+
+    let query = QueryBuilder::new()
+      .select(member.friends().handle.to_string())
+      .from(member)
+      .fetch(member.friends)
+      .build();
+
+    assert_eq!(query, "SELECT friends.handle FROM Member FETCH friends");
+
+    assert_eq!(
+      schema::define(),
+      "DEFINE TABLE Member; \
+      DEFINE FIELD handle ON Member; \
+      DEFINE FIELD friends ON Member TYPE array<record<Member>>;"
+    );
+  }
+}
+
+mod seven {
+  use surreal_simple_querybuilder::prelude::*;
+
+  surreal_simple_querybuilder::model!(Account {
+    id,
+    /// The unique "handle" of the account
+    pub handle,
+    /// The accounts this account likes
+    ->likes<{since, strength}>->Account as likes,
+    <-likes<{since}><-Account as liked_by,
+  });
+
+  #[test]
+  fn test_edge_properties() {
+    use schema::model as account;
+
+    assert_eq!(account.likes.to_string(), "->likes->Account");
+    assert_eq!(account.likes().since.to_string(), "->likes.since");
+    assert_eq!(account.likes().strength.to_string(), "->likes.strength");
+    assert_eq!(
+      account.likes().handle.to_string(),
+      "->likes->Account.handle"
+    );
+    assert_eq!(account.liked_by().since.to_string(), "<-likes.since");
+
+    assert_eq!(
+      account.likes().node().likes().since.to_string(),
+      "->likes->Account->likes.since"
+    );
+
+    let query = QueryBuilder::new()
+      .select(account.likes().since.to_string())
+      .from(account)
+      .build();
+
+    assert_eq!(query, "SELECT ->likes.since FROM Account");
+  }
+
+  #[test]
+  fn test_relations_const() {
+    assert_eq!(schema::RELATIONS, &["likes", "liked_by"]);
+
+    let (query, _) = select("*", "Account", Fetch(schema::RELATIONS)).unwrap();
+
+    assert_eq!(query, "SELECT * FROM Account FETCH likes , liked_by");
+  }
+
+  #[test]
+  fn test_relation_hops() {
+    use schema::model as account;
+
+    assert_eq!(account.likes.hops(2), "->likes->Account->likes->Account");
+    assert_eq!(account.liked_by.hops(2), "<-likes<-Account<-likes<-Account");
+    assert_eq!(
+      account.likes().node().likes.hops(2),
+      "->likes->Account->likes->Account->likes->Account"
+    );
+
+    let query = QueryBuilder::new()
+      .select(account.likes.hops(2))
+      .from(account)
+      .build();
+
+    assert_eq!(
+      query,
+      "SELECT ->likes->Account->likes->Account FROM Account"
+    );
+  }
+}
+
+mod eight {
+  use surreal_simple_querybuilder::prelude::*;
+  use surrealdb::sql::Thing;
+
+  surreal_simple_querybuilder::model!(User<Thing> with(data) {
+    id,
+    pub name: String,
+  });
+
+  #[test]
+  fn test_custom_id_type() {
+    let id = Thing::from(("user", "john"));
+    let user = schema::UserData {
+      id: Some(id.clone()),
+      name: "John".to_owned(),
+    };
+
+    assert_eq!(user.into_key().unwrap(), id);
+    assert!(matches!(
+      schema::UserData::default().into_key(),
+      Err(IntoKeyError::MissingId)
+    ));
+
+    let author: ForeignKey<schema::UserData, Thing> = ForeignKey::new_value(user);
+
+    assert_eq!(
+      serde_json::to_value(&author).unwrap(),
+      serde_json::to_value(&id).unwrap()
+    );
+  }
+}
+
+mod nine {
+  use serde::Serialize;
+  use surreal_simple_querybuilder::prelude::*;
+
+  surreal_simple_querybuilder::model!(Profile with(partial) {
+    id,
+    pub name: String,
+    pub age: u32,
+    pub bio: String,
+  });
+
+  #[test]
+  fn test_set_from_partial() {
+    let partial = schema::PartialProfile::new()
+      .name("John".to_owned())
+      .age(20);
+    let (query, params) = update("profile:john", Set(Build(partial))).unwrap();
+
+    assert_eq!(query, "UPDATE profile:john SET name = $name , age = $age");
+    assert_eq!(params.len(), 2);
+    assert_eq!(params.get("name"), Some(&serde_json::json!("John")));
+    assert_eq!(params.get("age"), Some(&serde_json::json!(20)));
+  }
+}
+
+mod ten {
+  use surreal_simple_querybuilder::prelude::*;
+
+  surreal_simple_querybuilder::model!(Account {
+    id,
+    pub handle,
+    pub email,
+  });
+
+  #[test]
+  fn test_record_link() {
+    let record = schema::Account::record("john");
+
+    assert_eq!(record.table(), "Account");
+    assert_eq!(record.to_string(), "Account:john");
+    assert_eq!(
+      serde_json::to_value(&record).unwrap(),
+      serde_json::json!({ "tb": "Account", "id": { "String": "john" } })
+    );
+    assert_eq!(
+      serde_json::to_value(schema::Account::record(10)).unwrap(),
+      serde_json::json!({ "tb": "Account", "id": { "Number": 10 } })
+    );
+  }
+
+  #[test]
+  fn test_aliased_projection() {
+    use schema::model as account;
+
+    let projection = Projection::new([(account.handle, "h"), (account.email, "e")]);
+    let (query, _) = select(projection, &account, ()).unwrap();
+
+    assert_eq!(query, "SELECT handle AS h , email AS e FROM Account");
+
+    let query = QueryBuilder::new()
+      .injecter(&Projection::new([(account.handle, "h")]))
+      .from(account)
+      .build();
+
+    assert_eq!(query, "SELECT handle AS h FROM Account");
+  }
+}
+
+mod eleven {
+  use surreal_simple_querybuilder::prelude::*;
+
+  surreal_simple_querybuilder::model!(Post {
+    id,
+    pub created_at,
+    pub updated_at,
+    pub published_at,
+  });
+
+  #[test]
+  fn test_column_comparison() {
+    use schema::model as post;
+
+    let filter = Where(Cmp("<", (post.created_at, Sql(post.updated_at))));
+    let (query, params) = select("*", &post, filter).unwrap();
+
+    assert_eq!(query, "SELECT * FROM Post WHERE created_at < updated_at");
+    assert!(params.is_empty());
+
+    let filter = Where((
+      Greater((post.updated_at, Sql(post.created_at))),
+      Lower((post.created_at, Sql(post.published_at))),
+      Equal((post.published_at, Sql(post.updated_at))),
+    ));
+    let (query, params) = select("*", &post, filter).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM Post WHERE updated_at > created_at \
+      AND created_at < published_at AND published_at = updated_at"
+    );
+    assert!(params.is_empty());
+  }
+}
+
+mod twelve {
+  use surreal_simple_querybuilder::prelude::*;
+
+  #[test]
+  fn test_dynamic_schema() {
+    let fields: Vec<String> = ["id", "title", "rating"]
+      .into_iter()
+      .map(ToOwned::to_owned)
+      .collect();
+    let schema = DynamicSchema::new(format!("{}_review", "tenant"), fields);
+    let title = schema.field("title").unwrap();
+    let rating = schema.field("rating").unwrap();
+
+    assert_eq!(schema.to_string(), "tenant_review");
+    assert!(schema.field("author").is_none());
+
+    let (query, params) =
+      select("*", &schema, Where(((title, "Dune"), Greater((rating, 3))))).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM tenant_review WHERE title = $title AND rating > $rating"
+    );
+    assert_eq!(params.get("title"), Some(&serde_json::json!("Dune")));
+    assert_eq!(params.get("rating"), Some(&serde_json::json!(3)));
+
+    let query = QueryBuilder::new()
+      .select_iter(schema.fields())
+      .from(&schema)
+      .build();
+
+    assert_eq!(query, "SELECT id , title , rating FROM tenant_review");
+  }
+
+  #[test]
+  fn test_dynamic_schema_escaping() {
+    let fields = vec!["name`; DELETE user; SELECT * FROM `x".to_owned()];
+    let schema = DynamicSchema::new("review; DELETE user", fields);
+    let name = schema
+      .field("name`; DELETE user; SELECT * FROM `x")
+      .unwrap();
+
+    let (query, _) = select("*", &schema, Where((name, "Dune"))).unwrap();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM `review; DELETE user` \
+      WHERE `name\\`; DELETE user; SELECT * FROM \\`x` = $name___DELETE_user__SELECT___FROM__x"
+    );
+  }
+}
+
+mod thirteen {
+  surreal_simple_querybuilder::model!(Tag as tag_schema with(schema, permissions(full)) {
+    id,
+    pub name: String,
+  });
+
+  surreal_simple_querybuilder::model!(Post as post_schema with(schema, permissions(
+    select: "published = true OR author = $auth.id",
+    create: "author = $auth.id",
+    update: "author = $auth.id",
+    delete: none,
+  )) {
+    id,
+    pub title: String,
+    pub published: bool,
+  });
+
+  #[test]
+  fn test_define_permissions() {
+    assert_eq!(
+      tag_schema::define(),
+      "DEFINE TABLE Tag PERMISSIONS FULL; DEFINE FIELD name ON Tag TYPE string;"
+    );
+
+    assert_eq!(
+      post_schema::define(),
+      "DEFINE TABLE Post PERMISSIONS \
+      FOR select WHERE published = true OR author = $auth.id \
+      FOR create, update WHERE author = $auth.id \
+      FOR delete NONE; \
+      DEFINE FIELD title ON Post TYPE string; \
+      DEFINE FIELD published ON Post TYPE bool;"
+    );
+  }
+}
+
+mod fourteen {
+  use serde::Deserialize;
+  use surreal_simple_querybuilder::prelude::*;
+
+  #[allow(dead_code)]
+  #[derive(Deserialize, Selectable)]
+  struct AccountPreview {
+    pub id: String,
+    pub(crate) handle: String,
+    #[serde(rename = "avatarUrl")]
+    avatar_url: Option<String>,
+    tags: std::collections::HashMap<String, Vec<String>>,
+    #[serde(skip)]
+    cached: bool,
+    r#type: String,
+  }
+
+  #[test]
+  fn test_select_as() {
+    assert_eq!(
+      AccountPreview::FIELDS,
+      &["id", "handle", "avatarUrl", "tags", "type"]
+    );
+
+    let query = QueryBuilder::new()
+      .select_as::<AccountPreview>()
+      .from("Account")
+      .build();
+
+    assert_eq!(
+      query,
+      "SELECT id , handle , avatarUrl , tags , type FROM Account"
+    );
+  }
+
+  #[allow(dead_code)]
+  #[derive(Deserialize, Selectable)]
+  #[serde(rename_all = "camelCase")]
+  struct AccountSettings {
+    display_name: String,
+    #[serde(rename = "avatar")]
+    avatar_url: String,
+    is_public_profile: bool,
+  }
+
+  #[allow(dead_code)]
+  #[derive(Deserialize, Selectable)]
+  #[serde(rename_all(serialize = "lowercase", deserialize = "SCREAMING-KEBAB-CASE"))]
+  struct LegacyAccount {
+    display_name: String,
+  }
+
+  #[allow(dead_code)]
+  #[derive(Deserialize, Selectable)]
+  struct RenamedAccount {
+    #[serde(rename(deserialize = "displayName"))]
+    display_name: String,
+    #[serde(rename(serialize = "avatarUrl"))]
+    avatar_url: String,
+  }
+
+  #[test]
+  fn test_select_as_rename_all() {
+    assert_eq!(
+      AccountSettings::FIELDS,
+      &["displayName", "avatar", "isPublicProfile"]
+    );
+    assert_eq!(LegacyAccount::FIELDS, &["DISPLAY-NAME"]);
+    assert_eq!(RenamedAccount::FIELDS, &["displayName", "avatar_url"]);
+
+    let query = QueryBuilder::new()
+      .select_as::<AccountSettings>()
+      .from("Account")
+      .build();
+    assert_eq!(
+      query,
+      "SELECT displayName , avatar , isPublicProfile FROM Account"
+    );
+
+    // the kebab-case names are escaped rather than read as a subtraction
+    let query = QueryBuilder::new()
+      .select_as::<LegacyAccount>()
+      .from("Account")
+      .build();
+    assert_eq!(query, "SELECT `DISPLAY-NAME` FROM Account");
+
+    let query = QueryBuilder::new()
+      .select_as::<RenamedAccount>()
+      .from("Account")
+      .build();
+    assert_eq!(query, "SELECT displayName , avatar_url FROM Account");
+  }
+}
@@ -0,0 +1,24 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+// run with `cargo test --no-default-features --features model`, the models must
+// compile without the `IntoKey` trait of the `foreign` feature.
+#![cfg(feature = "model")]
+#![cfg(not(feature = "foreign"))]
+
+use surreal_simple_querybuilder::prelude::*;
+
+surreal_simple_querybuilder::model!(User<String> with(data) {
+  id,
+  pub name: String,
+});
+
+#[test]
+fn test_custom_id_type_without_foreign() {
+  let user = schema::UserData {
+    id: Some("user:john".to_owned()),
+    name: "John".to_owned(),
+  };
+
+  assert_eq!(user.id.as_deref(), Some("user:john"));
+  assert_eq!(schema::model.name.to_string(), "name");
+}
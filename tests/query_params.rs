@@ -26,4 +26,56 @@ mod test {
     let (q, _bindings) = select("*", &model, filter).unwrap();
     assert_eq!("SELECT * FROM User WHERE name = $name AND age > $age", q);
   }
+
+  #[test]
+  fn test_bindings_checked() {
+    assert!(bindings_checked(Where(json!({ model.name: "John", model.age: 10 }))).is_ok());
+
+    let error = bindings_checked(Where(("1st", true))).unwrap_err();
+    assert_eq!(error.to_string(), "invalid parameter name `1st`");
+
+    let error = bindings_checked(Where(("first name", "John"))).unwrap_err();
+    assert_eq!(error.to_string(), "invalid parameter name `first name`");
+
+    assert!(bindings_checked(Bind(("auth", "John"))).is_err());
+  }
+
+  #[test]
+  fn test_merge_bindings() {
+    let a = bindings(Where(json!({ model.name: "John" }))).unwrap();
+    let b = bindings(Where(json!({ model.age: 10 }))).unwrap();
+    let merged = merge_bindings(a, b).unwrap();
+    assert_eq!(merged.get("name"), Some(&json!("John")));
+    assert_eq!(merged.get("age"), Some(&json!(10)));
+
+    let a = bindings(Where(json!({ model.name: "John" }))).unwrap();
+    let b = bindings(Where(json!({ model.name: "John", model.age: 10 }))).unwrap();
+    assert_eq!(merge_bindings(a, b).unwrap().len(), 2);
+
+    let a = bindings(Where(json!({ model.name: "John" }))).unwrap();
+    let b = bindings(Where(json!({ model.name: "Jane" }))).unwrap();
+    let error = merge_bindings(a, b).unwrap_err();
+    assert!(matches!(
+      &error,
+      QueryError::BindingCollision(BindingCollision(name)) if name == "name"
+    ));
+    assert_eq!(
+      error.to_string(),
+      "parameter `name` is bound to different values"
+    );
+  }
+
+  #[test]
+  fn test_bindings_sorted() {
+    let filter = || Where(json!({ model.name: "John", model.age: 10, model.id: "User:john" }));
+    let expected = vec![
+      ("age".to_owned(), json!(10)),
+      ("id".to_owned(), json!("User:john")),
+      ("name".to_owned(), json!("John")),
+    ];
+
+    for _ in 0..10 {
+      assert_eq!(bindings_sorted(filter()).unwrap(), expected);
+    }
+  }
 }
@@ -159,6 +159,35 @@ mod test {
     assert_eq!("->IS_FRIEND->Account:Mark", s);
   }
 
+  #[test]
+  pub fn test_nodebuilder_record_path() {
+    let mut s = "John".to_owned();
+    (&mut s)
+      .as_named_label("user")
+      .with("likes")
+      .with_id("post", "hello")
+      .from("wrote")
+      .from("user");
+
+    assert_eq!("user:John->likes->post:hello<-wrote<-user", s);
+  }
+
+  #[test]
+  pub fn test_nodebuilder_filter_last_segment() {
+    assert_eq!(
+      "account->manage->project_v2".filter("name = $name"),
+      "account->manage->(project_v2 WHERE name = $name)"
+    );
+    assert_eq!(
+      "account->manage->project.tasks".filter("done = true"),
+      "account->manage->project.(tasks WHERE done = true)"
+    );
+    assert_eq!(
+      "->manage->projet_été".to_owned().filter("archived = false"),
+      "->manage->(projet_été WHERE archived = false)"
+    );
+  }
+
   #[test]
   pub fn test_as_named_label() {
     let user_handle = "John";
@@ -365,4 +394,238 @@ mod test {
     assert_eq!("Account:an_id->manage->Project:other_id", query_two);
     assert_eq!(query_one, query_two);
   }
+
+  #[test]
+  fn test_build_validated() {
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("Account")
+      .filter("handle = $handle")
+      .and_group("age > 18", |q| q.or("admin = true"))
+      .build_validated();
+
+    assert_eq!(
+      Ok("SELECT * FROM Account WHERE handle = $handle AND ( age > 18 OR admin = true )"),
+      query.as_deref()
+    );
+
+    let empty_set = QueryBuilder::new()
+      .update("Account:john")
+      .set("")
+      .filter("handle = $handle")
+      .build_validated();
+
+    assert_eq!(Err(BuildError::EmptyClause("SET".to_owned())), empty_set);
+
+    let dangling_set = QueryBuilder::new()
+      .update("Account:john")
+      .set("")
+      .build_validated();
+    assert_eq!(Err(BuildError::EmptyClause("SET".to_owned())), dangling_set);
+
+    let unbalanced = QueryBuilder::new()
+      .select("count(")
+      .from("Account")
+      .build_validated();
+    assert_eq!(Err(BuildError::UnbalancedParentheses), unbalanced);
+  }
+
+  #[test]
+  fn test_skipped_first_condition() {
+    let name_filter: Option<&str> = None;
+
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("Account")
+      .filter(name_filter.unwrap_or_default())
+      .or("age > 10")
+      .and("age < 20")
+      .build();
+
+    assert_eq!(query, "SELECT * FROM Account WHERE age > 10 AND age < 20");
+
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("Account")
+      .filter("")
+      .and_group("", |q| q.or("age > 10").or("age < 5"))
+      .build();
+
+    assert_eq!(query, "SELECT * FROM Account WHERE ( age > 10 OR age < 5 )");
+  }
+
+  #[test]
+  fn test_nested_groups() {
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("Account")
+      .filter("verified = true")
+      .and_group("age > 18", |q| {
+        q.or_group("admin = true", |q| q.and("handle = $handle"))
+      })
+      .or_group("banned = false", |q| q.and("age < 10"))
+      .build_validated();
+
+    assert_eq!(
+      Ok(
+        "SELECT * FROM Account WHERE verified = true AND ( age > 18 OR ( admin = true AND handle = $handle ) ) \
+        OR ( banned = false AND age < 10 )"
+      ),
+      query.as_deref()
+    );
+  }
+
+  #[test]
+  fn test_raw_param() {
+    let fields = ["id", "handle"].join(" , ");
+    let limit = 10.to_string();
+
+    let query = QueryBuilder::new()
+      .select("{{fields}}")
+      .from("Account")
+      .limit("{{limit}}")
+      .raw_param("{{fields}}", fields)
+      .raw_param("{{limit}}", limit)
+      .param("Account", "User")
+      .build();
+
+    assert_eq!(query, "SELECT id , handle FROM User LIMIT 10");
+  }
+
+  #[test]
+  fn test_raw_param_containing_its_key() {
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("{{table}}")
+      .filter("name = '{{table}}'")
+      .raw_param("{{table}}", "archive_{{table}}".to_owned())
+      .build();
+
+    assert_eq!(
+      query,
+      "SELECT * FROM archive_{{table}} WHERE name = 'archive_{{table}}'"
+    );
+  }
+
+  #[test]
+  fn test_escape() {
+    assert_eq!(escape_ident("user_v2"), "user_v2");
+    assert_eq!(escape_ident("user-archive"), "`user-archive`");
+    assert_eq!(escape_ident("2023"), "`2023`");
+    assert_eq!(escape_ident("a`b"), "`a\\`b`");
+    assert_eq!(escape_ident("a\\b"), "`a\\\\b`");
+
+    // the ⟨⟩ delimiters have no escape sequence, so they are kept verbatim inside
+    // the backticks
+    assert_eq!(
+      escape_ident("x⟩; DELETE user; SELECT * FROM ⟨y"),
+      "`x⟩; DELETE user; SELECT * FROM ⟨y`"
+    );
+    assert_eq!(
+      escape_ident("x`; DELETE user; SELECT * FROM `y"),
+      "`x\\`; DELETE user; SELECT * FROM \\`y`"
+    );
+
+    assert_eq!(escape_string("John"), "'John'");
+    assert_eq!(escape_string("it's"), "'it\\'s'");
+    assert_eq!(escape_string("C:\\"), "'C:\\\\'");
+
+    let table = escape_ident("user-archive");
+    let (query, _) = delete(&table, ()).unwrap();
+    assert_eq!(query, "DELETE `user-archive`");
+
+    let query = QueryBuilder::new()
+      .select("*")
+      .from(escape_ident("user-archive"))
+      .filter(format!("name = {}", escape_string("O'Brien")))
+      .build();
+    assert_eq!(
+      query,
+      "SELECT * FROM `user-archive` WHERE name = 'O\\'Brien'"
+    );
+  }
+
+  #[test]
+  fn test_iter_variants() {
+    let fields: Vec<String> = vec!["handle".to_owned(), "email".to_owned()];
+    let fetched: Vec<String> = vec!["projects".to_owned(), "projects".to_owned()];
+
+    let query = QueryBuilder::new()
+      .select_iter(fields.clone())
+      .from("Account")
+      .group_by_iter(fields.iter().map(String::as_str))
+      .fetch_iter(fetched)
+      .build();
+
+    assert_eq!(
+      query,
+      "SELECT handle , email FROM Account GROUP BY handle , email FETCH projects"
+    );
+
+    let updates: Vec<String> = fields.iter().map(|f| format!("{f} = ${f}")).collect();
+    let query = QueryBuilder::new()
+      .update("Account")
+      .set_iter(updates)
+      .build();
+
+    assert_eq!(
+      query,
+      "UPDATE Account SET handle = $handle , email = $email"
+    );
+  }
+
+  #[test]
+  fn test_statements() {
+    let rows = [
+      ("user:a", "A", 10),
+      ("user:b", "B", 20),
+      ("user:c", "C", 30),
+    ];
+    let (query, params) = QueryBuilder::statements(rows, |(id, name, age)| {
+      (Update(id), Set((("name", name), ("age", age))))
+    })
+    .unwrap();
+
+    assert_eq!(
+      query,
+      "UPDATE user:a SET name = $name_0 , age = $age_0; \
+      UPDATE user:b SET name = $name_1 , age = $age_1; \
+      UPDATE user:c SET name = $name_2 , age = $age_2;"
+    );
+    assert_eq!(params.len(), 6);
+    assert_eq!(params.get("name_1"), Some(&serde_json::json!("B")));
+    assert_eq!(params.get("age_2"), Some(&serde_json::json!(30)));
+  }
+
+  #[test]
+  fn test_wrap_parens() {
+    let subquery = QueryBuilder::new()
+      .select("id")
+      .from("Account")
+      .filter("age > 18")
+      .wrap_parens()
+      .build();
+
+    assert_eq!(subquery, "( SELECT id FROM Account WHERE age > 18 )");
+
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("Post")
+      .filter(format!("author IN {subquery}"))
+      .build_validated();
+
+    assert_eq!(
+      query,
+      Ok("SELECT * FROM Post WHERE author IN ( SELECT id FROM Account WHERE age > 18 )".to_owned())
+    );
+
+    let query = QueryBuilder::new()
+      .select("*")
+      .from("Account")
+      .as_return()
+      .build();
+
+    assert_eq!(query, "RETURN ( SELECT * FROM Account )");
+    assert_eq!(QueryBuilder::new().wrap_parens().build(), "");
+  }
 }
@@ -125,8 +125,12 @@ mod test {
     Ok(response)
   }
 
-  /// There is currently a rough edge between the bindings from the querybuilder
-  /// and surrealdb itself because of the Serialize impl of [surrealdb::sql::Thing]
+  /// The `bind_surreal` function is only available with the `surrealdb` feature,
+  /// without it the record links are turned back into [Thing] by hand.
+  #[cfg(feature = "surrealdb")]
+  use surreal_simple_querybuilder::queries::bind_surreal as bind_params;
+
+  #[cfg(not(feature = "surrealdb"))]
   fn bind_params<N: surrealdb::Connection>(
     mut query: surrealdb::method::Query<N>,
     params: std::collections::HashMap<String, serde_json::Value>,
@@ -220,6 +224,15 @@ mod test {
     )
     .await?;
 
+    // the record links nested in an array are only handled by `bind_surreal`
+    #[cfg(feature = "surrealdb")]
+    {
+      let both_users_books_in: Vec<IBook> =
+        select(&book, Where(Cmp("IN", (book.author, [user0_id, user1_id])))).await?;
+
+      assert_eq!(both_users_books_in.len(), 15);
+    }
+
     assert_eq!(all_books.len(), 15);
     assert_eq!(user0_books.len(), 10);
     assert_eq!(user1_books.len(), 5);
@@ -267,6 +280,159 @@ mod test {
     Ok(())
   }
 
+  #[tokio::test]
+  async fn escaped_identifiers() -> DbResult<()> {
+    let db = Surreal::new::<Mem>(()).await?;
+    db.use_ns("namespace").use_db("database").await?;
+    db.query("CREATE user:john SET name = 'John'").await?;
+
+    for payload in [
+      "x⟩; DELETE user; SELECT * FROM ⟨y",
+      "x`; DELETE user; SELECT * FROM `y",
+      "x\\`; DELETE user; SELECT * FROM `y",
+    ] {
+      let table = escape_ident(payload);
+      let (query, _) = surreal_simple_querybuilder::queries::create(&table, ())?;
+      db.query(query).await?.check()?;
+
+      let tables: Vec<String> = db
+        .query(format!("SELECT VALUE meta::tb(id) FROM {table}"))
+        .await?
+        .take(0)?;
+      assert_eq!(tables, vec![payload.to_owned()]);
+    }
+
+    let users: Vec<String> = db.query("SELECT VALUE name FROM user").await?.take(0)?;
+    assert_eq!(users, vec!["John".to_owned()]);
+
+    Ok(())
+  }
+
+  #[cfg(feature = "surrealdb")]
+  #[tokio::test]
+  async fn foreign_key_record_link() -> DbResult<()> {
+    #[derive(Serialize)]
+    struct IPost {
+      title: String,
+      author: Foreign<String>,
+    }
+
+    let db = Surreal::new::<Mem>(()).await?;
+    db.use_ns("namespace").use_db("database").await?;
+    db.query("CREATE user:john SET name = 'John'").await?;
+
+    let post = IPost {
+      title: "Lorem Ipsum".to_owned(),
+      author: Foreign::new_key("user:john".to_owned()).with_record_ser(),
+    };
+
+    let (query, params) = surreal_simple_querybuilder::queries::create("post", Content(post))?;
+    bind_surreal(db.query(query), params).await?.check()?;
+
+    let names: Vec<String> = db
+      .query("SELECT VALUE author.name FROM post")
+      .await?
+      .take(0)?;
+
+    assert_eq!(names, vec!["John".to_owned()]);
+
+    Ok(())
+  }
+
+  #[cfg(feature = "surrealdb")]
+  #[tokio::test]
+  async fn relation_node_record_ids() -> DbResult<()> {
+    let db = Surreal::new::<Mem>(()).await?;
+    db.use_ns("namespace").use_db("database").await?;
+    db.query("CREATE user:john; CREATE post:hello; CREATE post:world")
+      .await?
+      .check()?;
+
+    let john = Thing::from(("user", "john"));
+    let hello = Thing::from(("post", "hello"));
+    let world = Thing::from(("post", "world"));
+    let statements = (
+      Prefixed("first", RelationNode::new(&john, "likes", &hello)),
+      Sql(";"),
+      Prefixed(
+        "second",
+        RelationNode::new(&john, "likes", &world).set(("since", 2023)),
+      ),
+    );
+    let query = surreal_simple_querybuilder::queries::query(&statements)?;
+    let params = bindings(statements)?;
+    bind_surreal(db.query(query), params).await?.check()?;
+
+    let mut liked: Vec<(Thing, Option<u32>)> = db
+      .query("SELECT VALUE [out, since] FROM likes WHERE in = user:john")
+      .await?
+      .take(0)?;
+    liked.sort_by_key(|(out, _)| out.to_string());
+
+    assert_eq!(liked, vec![(hello, None), (world, Some(2023))]);
+
+    Ok(())
+  }
+
+  #[cfg(feature = "surrealdb")]
+  #[tokio::test]
+  async fn model_record_link() -> DbResult<()> {
+    let db = Surreal::new::<Mem>(()).await?;
+    db.use_ns("namespace").use_db("database").await?;
+    db.query("CREATE User:john SET name = 'John'").await?;
+
+    let (query, params) =
+      surreal_simple_querybuilder::queries::create("Book", Set(("author", User::record("john"))))?;
+    bind_surreal(db.query(query), params).await?.check()?;
+
+    let names: Vec<String> = db
+      .query("SELECT VALUE author.name FROM Book")
+      .await?
+      .take(0)?;
+
+    assert_eq!(names, vec!["John".to_owned()]);
+
+    Ok(())
+  }
+
+  #[cfg(feature = "surrealdb")]
+  #[cfg(feature = "chrono")]
+  #[tokio::test]
+  async fn temporal_param_values() -> DbResult<()> {
+    use chrono::TimeZone;
+    use chrono::Utc;
+
+    let db = Surreal::new::<Mem>(()).await?;
+    db.use_ns("namespace").use_db("database").await?;
+
+    let created = SurrealDatetime(Utc.with_ymd_and_hms(2023, 1, 2, 3, 4, 5).unwrap());
+    let timeout = SurrealDuration(std::time::Duration::from_secs(90));
+    let (query, params) = surreal_simple_querybuilder::queries::create(
+      "event",
+      Set((
+        ("created", created),
+        ("timeout", timeout),
+        ("limits", json!({ "retry": timeout })),
+      )),
+    )?;
+    bind_surreal(db.query(query), params).await?.check()?;
+
+    let types: Vec<(bool, bool, bool)> = db
+      .query(
+        "SELECT VALUE [
+          type::is::datetime(created),
+          type::is::duration(timeout),
+          type::is::duration(limits.retry)
+        ] FROM event",
+      )
+      .await?
+      .take(0)?;
+
+    assert_eq!(types, vec![(true, true, true)]);
+
+    Ok(())
+  }
+
   async fn create_books(author_id: &Thing, amount: usize) -> DbResult<()> {
     for i in 0..amount {
       create(